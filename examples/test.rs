@@ -4,7 +4,7 @@ use firecore_battle::pokedex::pokemon::PokemonTexture;
 use ron::ser::PrettyConfig;
 
 fn main() {
-    let generated = firecore_dex_gen::generate();
+    let generated = firecore_dex_gen::generate().unwrap();
 
     println!("Pokemon: {}", generated.pokemon.pokemon.len());
 
@@ -14,6 +14,14 @@ fn main() {
 
     println!("Battle Moves: {}", generated.moves.execution.len());
 
+    for entry in &generated.report.skipped {
+        println!("Skipped {}", entry);
+    }
+
+    for entry in &generated.report.failed {
+        println!("Failed {}", entry);
+    }
+
     std::fs::create_dir_all("generated/pokemon").unwrap();
 
     let pokemon = Path::new("generated/client/pokemon");
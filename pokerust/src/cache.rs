@@ -0,0 +1,190 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, SystemTime},
+};
+
+/// Counter making the temporary files of concurrent [`Cache::insert`] calls unique.
+static TEMP_FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// On-disk cache of API responses, keyed by endpoint path.
+///
+/// Each response is stored as a JSON file under the cache directory, laid out
+/// like the endpoint path itself, e.g. `pokemon/25/` is stored at
+/// `pokemon/25/index.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+    ttl: Option<Duration>,
+}
+
+impl Cache {
+    /// Create a cache stored in the given directory.
+    /// Entries never expire unless a TTL is set with [`Cache::with_ttl`].
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl: None,
+        }
+    }
+
+    /// Set how long an entry stays valid after it was written.
+    #[must_use]
+    pub const fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    /// The directory this cache is stored in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The time entries stay valid for, if they expire at all.
+    #[must_use]
+    pub const fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Get the cached body for an endpoint path, if it exists and has not expired.
+    #[must_use]
+    pub fn get(&self, loc: &str) -> Option<String> {
+        let path = self.path(loc);
+        if let Some(ttl) = self.ttl {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            let age = SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+            if age > ttl {
+                return None;
+            }
+        }
+        fs::read_to_string(path).ok()
+    }
+
+    /// Store the body for an endpoint path.
+    ///
+    /// The body is written to a temporary file first and then moved into place,
+    /// so other threads and processes never read a partially written entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry could not be written to disk.
+    pub fn insert(&self, loc: &str, body: &str) -> io::Result<()> {
+        let path = self.path(loc);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut temp = path.clone().into_os_string();
+        temp.push(format!(
+            ".{}-{}.tmp",
+            std::process::id(),
+            TEMP_FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let temp = PathBuf::from(temp);
+        if let Err(err) = fs::write(&temp, body).and_then(|()| fs::rename(&temp, &path)) {
+            let _ = fs::remove_file(&temp);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Remove the entry for an endpoint path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry exists but could not be removed.
+    pub fn remove(&self, loc: &str) -> io::Result<()> {
+        match fs::remove_file(self.path(loc)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Remove every entry in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory could not be removed.
+    pub fn purge(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get the file an endpoint path is stored at.
+    pub(crate) fn path(&self, loc: &str) -> PathBuf {
+        self.dir.join(file_from_loc(loc))
+    }
+}
+
+impl Default for Cache {
+    /// A cache in the `pokerust` folder of the system's temporary directory.
+    fn default() -> Self {
+        Self::new(std::env::temp_dir().join("pokerust"))
+    }
+}
+
+/// Get the relative file an endpoint path is stored at,
+/// e.g. "pokemon/?offset=0&limit=20" becomes "pokemon/index-offset=0&limit=20.json"
+fn file_from_loc(loc: &str) -> PathBuf {
+    let (path, query) = match loc.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (loc, None),
+    };
+    let mut file = PathBuf::new();
+    file.extend(path.split('/').filter(|s| !s.is_empty()));
+    match query {
+        Some(query) if !query.is_empty() => file.push(format!("index-{query}.json")),
+        _ => file.push("index.json"),
+    }
+    file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_from_loc() {
+        assert_eq!(
+            file_from_loc("pokemon/25/"),
+            Path::new("pokemon").join("25").join("index.json")
+        );
+        assert_eq!(
+            file_from_loc("ability/?offset=20&limit=20"),
+            Path::new("ability").join("index-offset=20&limit=20.json")
+        );
+        assert_eq!(
+            file_from_loc("pokemon/25/encounters"),
+            Path::new("pokemon")
+                .join("25")
+                .join("encounters")
+                .join("index.json")
+        );
+    }
+
+    #[test]
+    fn test_insert_get_purge() {
+        let dir = std::env::temp_dir().join(format!("pokerust-cache-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+
+        assert_eq!(cache.get("berry/1/"), None);
+        cache.insert("berry/1/", "{}").unwrap();
+        assert_eq!(cache.get("berry/1/").as_deref(), Some("{}"));
+
+        let expired = Cache::new(&dir).with_ttl(Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(10));
+        assert_eq!(expired.get("berry/1/"), None);
+
+        cache.remove("berry/1/").unwrap();
+        assert_eq!(cache.get("berry/1/"), None);
+
+        cache.insert("berry/2/", "{}").unwrap();
+        cache.purge().unwrap();
+        assert!(!dir.exists());
+    }
+}
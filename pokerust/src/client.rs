@@ -1,44 +1,283 @@
+use std::{collections::HashSet, num::NonZeroUsize, sync::Mutex, time::Duration};
+
 use serde::{Serialize, de::DeserializeOwned};
 
-use crate::Endpoint;
+use crate::{Cache, Endpoint, Error, Http, Iter, Mirror, Pages, Recorder, Replay, Resource, Transport};
 
 pub struct Client {
-    pub endpoint: String,
-    // pub(crate) client: attohttpc::,
+    /// Backend API objects are retrieved from.
+    pub transport: Box<dyn Transport>,
+    /// Cache for API responses. Set to `None` to always fetch from the transport.
+    pub cache: Option<Cache>,
+    /// How many API objects are fetched at once by [`Client::get_many`] and [`Client::resolve_many`].
+    pub workers: usize,
 }
 
 impl Client {
 
-    pub(crate) fn get_api_loc<T: DeserializeOwned>(&self, loc: &str) -> Result<T, attohttpc::Error> {
-        attohttpc::get(format!("{}{}", self.endpoint, crate::pokeapi::get_api_loc_from_url(loc))).send()?.json::<T>()
+    /// Retrieve API objects through the given transport, without a cache.
+    pub fn new(transport: impl Transport + 'static) -> Self {
+        Self {
+            transport: Box::new(transport),
+            cache: None,
+            workers: std::thread::available_parallelism().map_or(4, NonZeroUsize::get),
+        }
+    }
+
+    /// Use the given cache for API responses.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fetch up to the given number of API objects at once in [`Client::get_many`] and [`Client::resolve_many`].
+    #[must_use]
+    pub const fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers;
+        self
+    }
+
+    /// Fetch the API object at a location relative to the API root, using the cache if there is one.
+    ///
+    /// Cached responses that cannot be decoded are fetched again and replaced.
+    pub(crate) fn fetch<T: DeserializeOwned>(&self, loc: &str) -> Result<T, Error> {
+        if let Some(body) = self.cache.as_ref().and_then(|cache| cache.get(loc)) {
+            if let Ok(value) = decode(loc, &body) {
+                return Ok(value);
+            }
+        }
+        let body = self.transport.get(loc)?;
+        let value = decode(loc, &body)?;
+        if let Some(cache) = &self.cache {
+            // The cache only saves requests, so the response is still used if it cannot be written.
+            let _ = cache.insert(loc, &body);
+        }
+        Ok(value)
+    }
+
+    pub(crate) fn get_api_loc<T: DeserializeOwned>(&self, loc: &str) -> Result<T, Error> {
+        self.fetch(crate::pokeapi::get_api_loc_from_url(loc))
     }
 
     /// Retrieve the API object of this type with a name/id.
-    pub fn get<E: Endpoint + DeserializeOwned + Serialize, I: std::fmt::Display>(&self, id: I) -> Result<E, attohttpc::Error> {
-        attohttpc::get(format!("{}{}/{}/", self.endpoint, E::ENDPOINT, id)).send()?.json::<E>()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API object could not be retrieved or decoded, see [`Error`].
+    pub fn get<E: Endpoint + DeserializeOwned + Serialize, I: std::fmt::Display>(&self, id: I) -> Result<E, Error> {
+        self.fetch(&format!("{}/{}/", E::ENDPOINT, id))
+    }
+
+    /// Retrieve the API objects of this type with each of the given names/ids,
+    /// fetching up to [`Client::workers`] of them at once.
+    ///
+    /// Repeated names/ids are only fetched once. Returns each name/id with the result of
+    /// retrieving it, in the order they first appear.
+    pub fn get_many<E, I>(&self, ids: impl IntoIterator<Item = I>) -> Vec<(I, Result<E, Error>)>
+    where
+        E: Endpoint + DeserializeOwned + Serialize + Send,
+        I: std::fmt::Display + Send,
+    {
+        let mut seen = HashSet::new();
+        let ids = ids
+            .into_iter()
+            .filter(|id| seen.insert(id.to_string()))
+            .collect();
+        self.for_each(ids, |id| {
+            let result = self.get(&id);
+            (id, result)
+        })
+    }
+
+    /// Fetch the API object each of the given resources points to,
+    /// fetching up to [`Client::workers`] of them at once.
+    ///
+    /// Resources pointing to the same API object are only fetched once. Returns each resource
+    /// with the result of fetching it, in the order they first appear.
+    pub fn resolve_many<'a, R>(&self, resources: impl IntoIterator<Item = &'a R>) -> Vec<(&'a R, Result<R::Target, Error>)>
+    where
+        R: Resource + Sync + 'a,
+        R::Target: Send,
+    {
+        let mut seen = HashSet::new();
+        let resources = resources
+            .into_iter()
+            .filter(|resource| seen.insert(resource.url()))
+            .collect();
+        self.for_each(resources, |resource| (resource, resource.resolve(self)))
+    }
+
+    /// Call a function on every item across [`Client::workers`] threads, keeping the results in order.
+    fn for_each<T: Send, R: Send>(&self, items: Vec<T>, f: impl Fn(T) -> R + Sync) -> Vec<R> {
+        let len = items.len();
+        let items = Mutex::new(items.into_iter().enumerate());
+        let mut results = std::thread::scope(|scope| {
+            let workers = (0..self.workers.clamp(1, len.max(1)))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut results = Vec::new();
+                        loop {
+                            let next = items.lock().unwrap().next();
+                            match next {
+                                Some((index, item)) => results.push((index, f(item))),
+                                None => break results,
+                            }
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap())
+                .collect::<Vec<_>>()
+        });
+        results.sort_unstable_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
     }
 
     /// Get a list of these API objects with the given offset and limit.
-    pub fn list<E: Endpoint + DeserializeOwned + Serialize>(&self, offset: usize, limit: usize) -> Result<E::ResourceListKind, attohttpc::Error> {
-        attohttpc::get(format!("{}{}/?offset={}&limit={}", self.endpoint, E::ENDPOINT, offset, limit)).send()?.json::<E::ResourceListKind>()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API object could not be retrieved or decoded, see [`Error`].
+    pub fn list<E: Endpoint + DeserializeOwned + Serialize>(&self, offset: usize, limit: usize) -> Result<E::ResourceListKind, Error> {
+        self.fetch(&format!("{}/?offset={}&limit={}", E::ENDPOINT, offset, limit))
+    }
+
+    /// Iterate over every resource in the list of these API objects, fetching 100 at a time.
+    #[must_use]
+    pub fn iter<E: Endpoint + DeserializeOwned + Serialize>(&self) -> Iter<'_, E::ResourceListKind> {
+        self.iter_with_limit::<E>(100)
+    }
+
+    /// Iterate over every resource in the list of these API objects, fetching the given number at a time.
+    #[must_use]
+    pub fn iter_with_limit<E: Endpoint + DeserializeOwned + Serialize>(&self, limit: usize) -> Iter<'_, E::ResourceListKind> {
+        Iter::new(self.pages::<E>(limit))
+    }
+
+    /// Iterate over the pages of the list of these API objects with the given limit.
+    #[must_use]
+    pub fn pages<E: Endpoint + DeserializeOwned + Serialize>(&self, limit: usize) -> Pages<'_, E::ResourceListKind> {
+        Pages::new(self, format!("{}/?offset=0&limit={}", E::ENDPOINT, limit))
     }
 
     /// Get the complete list of these API objects.
-    pub fn full_list<E: Endpoint + DeserializeOwned + Serialize>(&self) -> Result<E::ResourceListKind, attohttpc::Error> {
-        attohttpc::get(format!("{}{}/?offset=0&limit=9999", self.endpoint, E::ENDPOINT)).send()?.json::<E::ResourceListKind>()
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API object could not be retrieved or decoded, see [`Error`].
+    pub fn full_list<E: Endpoint + DeserializeOwned + Serialize>(&self) -> Result<E::ResourceListKind, Error> {
+        self.fetch(&format!("{}/?offset=0&limit=9999", E::ENDPOINT))
     }
 
 }
 
 impl Default for Client {
+    /// Serve API objects from the fixtures in the `POKERUST_REPLAY` environment variable if it is set,
+    /// or read them from the mirror in `POKERUST_MIRROR` if that is set,
+    /// otherwise request them from the endpoint in `POKERUST_ENDPOINT` and cache them on disk.
+    ///
+    /// If `POKERUST_RECORD` is set, every API object retrieved is also recorded as a fixture in that directory.
+    /// The cache is not used while recording, so API objects that were already cached are recorded too.
     fn default() -> Self {
-        Self {
-            endpoint: match std::env::var("POKERUST_ENDPOINT") {
-                Ok(val) => val,
-                Err(std::env::VarError::NotPresent) => String::from("https://pokeapi.co/api/v2/"),
-                Err(err) => panic!("Could not read endpoint from POKERUST_ENDPOINT with error {}", err),
+        let client = std::env::var_os("POKERUST_REPLAY")
+            .map(|dir| Self::new(Replay::new(dir)))
+            .or_else(|| std::env::var_os("POKERUST_MIRROR").map(|dir| Self::new(Mirror::new(dir))))
+            .unwrap_or_else(|| Self::new(Http::default()).with_cache(default_cache()));
+        match std::env::var_os("POKERUST_RECORD") {
+            Some(dir) => Self {
+                transport: Box::new(Recorder::new(client.transport, dir)),
+                cache: None,
+                ..client
             },
-            // client: reqwest::Client::new(),
+            None => client,
         }
     }
-}
\ No newline at end of file
+}
+
+/// Cache configured by the `POKERUST_CACHE` and `POKERUST_CACHE_TTL` environment variables.
+fn default_cache() -> Cache {
+    let cache = std::env::var_os("POKERUST_CACHE").map_or_else(Cache::default, Cache::new);
+    match std::env::var("POKERUST_CACHE_TTL") {
+        Ok(val) => cache.with_ttl(Duration::from_secs(val.parse().unwrap_or_else(|err| {
+            panic!("Could not parse cache TTL from POKERUST_CACHE_TTL with error {err}")
+        }))),
+        Err(std::env::VarError::NotPresent) => cache,
+        Err(err) => panic!("Could not read cache TTL from POKERUST_CACHE_TTL with error {err}"),
+    }
+}
+
+fn decode<T: DeserializeOwned>(loc: &str, body: &str) -> Result<T, Error> {
+    serde_json::from_str(body).map_err(|source| Error::Decode {
+        path: loc.to_owned(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use super::*;
+    use crate::{Language, Memory, Named, NamedAPIResource};
+
+    const EN: &str = r#"{"id":9,"name":"en","official":true,"iso639":"en","iso3166":"gb","names":[]}"#;
+
+    struct Counting(Memory, Arc<AtomicUsize>);
+
+    impl Transport for Counting {
+        fn get(&self, loc: &str) -> Result<String, Error> {
+            self.1.fetch_add(1, Ordering::Relaxed);
+            self.0.get(loc)
+        }
+    }
+
+    #[test]
+    fn test_get_many() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = Client::new(Counting(Memory::new().with("language/9/", EN), requests.clone())).with_workers(2);
+        let languages = client.get_many::<Language, _>([9, 10, 9]);
+
+        assert_eq!(requests.load(Ordering::Relaxed), 2);
+        assert_eq!(languages.len(), 2);
+        assert_eq!(languages[0].0, 9);
+        assert_eq!(languages[0].1.as_ref().unwrap().name(), "en");
+        assert_eq!(languages[1].0, 10);
+        assert!(languages[1].1.as_ref().unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_resolve_many() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let client = Client::new(Counting(Memory::new().with("language/9/", EN), requests.clone()));
+        let resources: Vec<NamedAPIResource<Language>> = serde_json::from_str(
+            r#"[{"name":"en","url":"https://pokeapi.co/api/v2/language/9/"},
+            {"name":"en","url":"https://pokeapi.co/api/v2/language/9/"}]"#,
+        )
+        .unwrap();
+        let languages = client.resolve_many(&resources);
+
+        assert_eq!(requests.load(Ordering::Relaxed), 1);
+        assert_eq!(languages.len(), 1);
+        assert_eq!(languages[0].1.as_ref().unwrap().name(), "en");
+    }
+
+    #[test]
+    fn test_fetch_replaces_corrupt_cache_entry() {
+        let dir = std::env::temp_dir().join(format!("pokerust-client-test-{}", std::process::id()));
+        let cache = Cache::new(&dir);
+        cache.insert("language/9/", r#"{"id":9,"na"#).unwrap();
+
+        let client = Client::new(Memory::new().with("language/9/", EN)).with_cache(cache.clone());
+        let language = client.get::<Language, _>(9).unwrap();
+
+        assert_eq!(language.name(), "en");
+        assert_eq!(cache.get("language/9/").as_deref(), Some(EN));
+        cache.purge().unwrap();
+    }
+}
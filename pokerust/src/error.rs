@@ -0,0 +1,101 @@
+use std::{error, fmt, io};
+
+/// Error returned when an API object could not be retrieved.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// There is no API object at this path.
+    NotFound { path: String },
+    /// The API responded with an unsuccessful status code.
+    Status { path: String, status: u16 },
+    /// The response could not be decoded into the expected API object,
+    /// usually because the API's schema has changed.
+    Decode {
+        path: String,
+        source: serde_json::Error,
+    },
+    /// The request could not be sent or its response could not be read.
+    Transport {
+        path: String,
+        source: attohttpc::Error,
+    },
+    /// The API object was never recorded as a fixture, see [`Replay`](crate::Replay).
+    Unrecorded { path: String },
+    /// The API object could not be read from or written to a local directory.
+    Io { path: String, source: io::Error },
+}
+
+impl Error {
+    /// The path, relative to the endpoint, of the API object that could not be retrieved.
+    #[must_use]
+    pub fn path(&self) -> &str {
+        match self {
+            Self::NotFound { path }
+            | Self::Unrecorded { path }
+            | Self::Status { path, .. }
+            | Self::Decode { path, .. }
+            | Self::Transport { path, .. }
+            | Self::Io { path, .. } => path,
+        }
+    }
+
+    /// Whether this error means the API object does not exist.
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+
+    /// Whether the request may succeed if it is sent again,
+    /// e.g. after being rate limited, a server error or a timeout.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Status { status, .. } => *status == 429 || *status >= 500,
+            Self::Transport { source, .. } => match source.kind() {
+                attohttpc::ErrorKind::Io(err) => matches!(
+                    err.kind(),
+                    io::ErrorKind::TimedOut
+                        | io::ErrorKind::WouldBlock
+                        | io::ErrorKind::Interrupted
+                        | io::ErrorKind::ConnectionReset
+                        | io::ErrorKind::ConnectionAborted
+                        | io::ErrorKind::UnexpectedEof
+                ),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound { path } => write!(f, "No API object found at {path}"),
+            Self::Status { path, status } => {
+                write!(f, "API responded with status {status} for {path}")
+            }
+            Self::Decode { path, source } => {
+                write!(f, "Could not decode API object at {path}: {source}")
+            }
+            Self::Transport { path, source } => {
+                write!(f, "Could not request API object at {path}: {source}")
+            }
+            Self::Unrecorded { path } => write!(f, "No fixture recorded for {path}"),
+            Self::Io { path, source } => {
+                write!(f, "Could not read or write API object at {path}: {source}")
+            }
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::NotFound { .. } | Self::Unrecorded { .. } | Self::Status { .. } => None,
+            Self::Decode { source, .. } => Some(source),
+            Self::Transport { source, .. } => Some(source),
+            Self::Io { source, .. } => Some(source),
+        }
+    }
+}
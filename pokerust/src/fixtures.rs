@@ -0,0 +1,139 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Cache, Error, Transport};
+
+/// Transport that records every API object retrieved through another transport
+/// as a fixture, to be served later by [`Replay`].
+///
+/// Fixtures are laid out like the [`Cache`](crate::Cache), e.g. `pokemon/25/` is
+/// recorded at `pokemon/25/index.json`. Locations with no API object are recorded
+/// with an empty `index.missing` file instead.
+#[derive(Debug)]
+pub struct Recorder<T: Transport> {
+    inner: T,
+    dir: PathBuf,
+}
+
+impl<T: Transport> Recorder<T> {
+    /// Record API objects retrieved through a transport into the given directory.
+    pub fn new(inner: T, dir: impl Into<PathBuf>) -> Self {
+        Self {
+            inner,
+            dir: dir.into(),
+        }
+    }
+
+    /// The directory fixtures are recorded in.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn record(loc: &str, file: &Path, body: &str) -> Result<(), Error> {
+        let write = || {
+            if let Some(parent) = file.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(file, body)
+        };
+        write().map_err(|source| Error::Io {
+            path: loc.to_owned(),
+            source,
+        })
+    }
+}
+
+impl<T: Transport> Transport for Recorder<T> {
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        let file = Cache::new(&self.dir).path(loc);
+        match self.inner.get(loc) {
+            Ok(body) => {
+                Self::record(loc, &file, &body)?;
+                Ok(body)
+            }
+            Err(err) if err.is_not_found() => {
+                Self::record(loc, &file.with_extension("missing"), "")?;
+                Err(err)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Transport that serves API objects only from fixtures written by a [`Recorder`].
+///
+/// Locations that were never recorded return [`Error::Unrecorded`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    dir: PathBuf,
+}
+
+impl Replay {
+    /// Serve the fixtures in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory fixtures are served from.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Transport for Replay {
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        let file = Cache::new(&self.dir).path(loc);
+        match fs::read_to_string(&file) {
+            Ok(body) => Ok(body),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let path = loc.to_owned();
+                Err(if file.with_extension("missing").exists() {
+                    Error::NotFound { path }
+                } else {
+                    Error::Unrecorded { path }
+                })
+            }
+            Err(source) => Err(Error::Io {
+                path: loc.to_owned(),
+                source,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Memory;
+
+    #[test]
+    fn test_record_replay() {
+        let dir = std::env::temp_dir().join(format!("pokerust-fixtures-test-{}", std::process::id()));
+        let recorder = Recorder::new(
+            Memory::new()
+                .with("berry/1/", r#"{"id":1}"#)
+                .with("berry/?offset=0&limit=20", r#"{"count":1}"#),
+            &dir,
+        );
+
+        assert_eq!(recorder.get("berry/1/").unwrap(), r#"{"id":1}"#);
+        assert_eq!(recorder.get("berry/?offset=0&limit=20").unwrap(), r#"{"count":1}"#);
+        assert!(recorder.get("berry/2/").unwrap_err().is_not_found());
+
+        let replay = Replay::new(&dir);
+
+        assert_eq!(replay.get("berry/1/").unwrap(), r#"{"id":1}"#);
+        assert_eq!(replay.get("berry/?offset=0&limit=20").unwrap(), r#"{"count":1}"#);
+        assert!(replay.get("berry/2/").unwrap_err().is_not_found());
+        assert!(matches!(
+            replay.get("berry/3/"),
+            Err(Error::Unrecorded { .. })
+        ));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use std::marker::PhantomData;
+
+use crate::{Client, Error, List, Resource};
+
+/// Iterator over the pages of a resource list, following each page's `next` url.
+/// Pages are only fetched when the iterator reaches them.
+pub struct Pages<'a, L: List> {
+    client: &'a Client,
+    next: Option<String>,
+    list: PhantomData<L>,
+}
+
+impl<'a, L: List> Pages<'a, L> {
+    /// Iterate over pages starting from the list at a location relative to the API root.
+    pub(crate) const fn new(client: &'a Client, loc: String) -> Self {
+        Self {
+            client,
+            next: Some(loc),
+            list: PhantomData,
+        }
+    }
+}
+
+impl<L> Iterator for Pages<'_, L>
+where
+    L: List + serde::de::DeserializeOwned,
+{
+    type Item = Result<L, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let loc = self.next.take()?;
+        let page = self.client.fetch::<L>(&loc);
+        if let Ok(page) = &page {
+            self.next = page
+                .next_url()
+                .map(|url| crate::pokeapi::get_api_loc_from_url(url).to_owned());
+        }
+        Some(page)
+    }
+}
+
+/// Iterator over every resource in a resource list, fetching pages as they are needed.
+///
+/// If a page could not be fetched, its error is returned and iteration stops.
+pub struct Iter<'a, L: List> {
+    pages: Pages<'a, L>,
+    results: std::vec::IntoIter<L::Resource>,
+}
+
+impl<'a, L: List> Iter<'a, L> {
+    pub(crate) fn new(pages: Pages<'a, L>) -> Self {
+        Self {
+            pages,
+            results: Vec::new().into_iter(),
+        }
+    }
+
+    /// Fetch the API object each resource points to.
+    #[must_use]
+    pub const fn resolve(self) -> Resolve<'a, L> {
+        Resolve { iter: self }
+    }
+}
+
+impl<L> Iterator for Iter<'_, L>
+where
+    L: List + serde::de::DeserializeOwned,
+{
+    type Item = Result<L::Resource, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(resource) = self.results.next() {
+                return Some(Ok(resource));
+            }
+            match self.pages.next()? {
+                Ok(page) => self.results = page.into_results().into_iter(),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+    }
+}
+
+/// Iterator over the API objects every resource in a resource list points to.
+pub struct Resolve<'a, L: List> {
+    iter: Iter<'a, L>,
+}
+
+impl<L> Iterator for Resolve<'_, L>
+where
+    L: List + serde::de::DeserializeOwned,
+{
+    type Item = Result<<L::Resource as Resource>::Target, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let client = self.iter.pages.client;
+        Some(self.iter.next()?.and_then(|resource| resource.resolve(client)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Berry, Client, List, Memory, Named, NamedAPIResourceList};
+
+    fn client() -> Client {
+        let list = |offset: usize, names: &[&str], next: Option<usize>, previous: Option<usize>| {
+            let page = |offset| format!("\"https://pokeapi.co/api/v2/berry/?offset={offset}&limit=2\"");
+            let results = names
+                .iter()
+                .enumerate()
+                .map(|(index, name)| {
+                    format!(
+                        r#"{{"name":"{}","url":"https://pokeapi.co/api/v2/berry/{}/"}}"#,
+                        name,
+                        offset + index + 1
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            format!(
+                r#"{{"count":3,"next":{},"previous":{},"results":[{}]}}"#,
+                next.map_or_else(|| "null".to_owned(), page),
+                previous.map_or_else(|| "null".to_owned(), page),
+                results
+            )
+        };
+        Client::new(
+            Memory::new()
+                .with("berry/?offset=0&limit=2", list(0, &["cheri", "chesto"], Some(2), None))
+                .with("berry/?offset=2&limit=2", list(2, &["pecha"], None, Some(0)))
+                .with("berry/1/", r#"{"id":1,"name":"cheri","growth_time":3,"max_harvest":5,"natural_gift_power":60,"size":20,"smoothness":25,"soil_dryness":15,"firmness":{"name":"soft","url":"https://pokeapi.co/api/v2/berry-firmness/2/"},"flavors":[],"item":{"name":"cheri-berry","url":"https://pokeapi.co/api/v2/item/126/"},"natural_gift_type":{"name":"fire","url":"https://pokeapi.co/api/v2/type/10/"}}"#),
+        )
+    }
+
+    #[test]
+    fn test_iter() {
+        let client = client();
+        let names = client
+            .iter_with_limit::<Berry>(2)
+            .map(|resource| resource.unwrap().name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["cheri", "chesto", "pecha"]);
+
+        let mut berries = client.iter_with_limit::<Berry>(2).resolve();
+        assert_eq!(berries.next().unwrap().unwrap().name(), "cheri");
+        assert!(berries.next().unwrap().unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_next_previous_list() {
+        let client = client();
+        let first: NamedAPIResourceList<Berry> = client.list::<Berry>(0, 2).unwrap();
+        let second = first.next_list(&client).unwrap().unwrap();
+        assert_eq!(second.results()[0].name, "pecha");
+        assert_eq!(second.next_list(&client).unwrap(), None);
+        assert_eq!(second.previous_list(&client).unwrap(), Some(first));
+    }
+}
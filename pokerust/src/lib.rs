@@ -20,7 +20,18 @@
 //!     let berry = Berry::from_name("cheri").unwrap();
 //! }
 //! ```
-//! API responses are automatically cached.
+//! API responses are automatically cached on disk, in the `pokerust` folder of
+//! the system's temporary directory. Use [`Client::with_cache`] to configure the
+//! cache directory and how long entries stay valid, or set `cache` to `None` to
+//! disable it:
+//! ```no_run
+//! # use std::time::Duration;
+//! # use pokerust::{Cache, Client};
+//! let client = Client::default().with_cache(Cache::new("cache").with_ttl(Duration::from_secs(86400)));
+//!
+//! // remove every cached response
+//! client.cache.as_ref().unwrap().purge().unwrap();
+//! ```
 //!
 //! You can also fetch the resource lists:
 //! ```no_run
@@ -39,6 +50,32 @@
 //! # }
 //! ```
 //!
+//! Or iterate over every resource in a list, fetching pages as they are needed:
+//! ```no_run
+//! # use pokerust::{Client, Move};
+//! let client = Client::default();
+//!
+//! for move_ in client.iter::<Move>() {
+//!     println!("{}", move_.unwrap().name);
+//! }
+//!
+//! // or fetch the API object each resource points to
+//! let moves = client.iter::<Move>().resolve().collect::<Result<Vec<_>, _>>().unwrap();
+//! ```
+//!
+//! Many API objects can be fetched at once, with each result returned separately:
+//! ```no_run
+//! # use pokerust::{Client, Pokemon};
+//! let client = Client::default().with_workers(4);
+//!
+//! for (id, pokemon) in client.get_many::<Pokemon, _>(1..=151) {
+//!     match pokemon {
+//!         Ok(pokemon) => println!("{}: {}", id, pokemon.name),
+//!         Err(err) => eprintln!("{}: {}", id, err),
+//!     }
+//! }
+//! ```
+//!
 //! To get resources pointed to by `(Named)APIResource`, use `get()`:
 //! ```no_run
 //! # use std::error::Error;
@@ -49,6 +86,17 @@
 //! # Ok(())
 //! # }
 //! ```
+//! Lists of text in different languages, such as names and flavor text, can be
+//! searched by language with a fallback chain, and by version or version group:
+//! ```no_run
+//! # use pokerust::{Client, Localized, PokemonSpecies};
+//! let client = Client::default();
+//! let bulbasaur = client.get::<PokemonSpecies, _>(1).unwrap();
+//!
+//! let name = bulbasaur.names.localized_in(&["de", "en"]);
+//! let entry = bulbasaur.flavor_text_entries.localized_for_version(&["en"], "firered");
+//! ```
+//!
 //! This can be chained:
 //! ```no_run
 //! # use std::error::Error;
@@ -64,16 +112,68 @@
 //! POKERUST_ENDPOINT environment variable. Defaults to the public instance at
 //! <https://pokeapi.co/api/v2/>. Please consult the pokeapi documentation and read
 //! the fair use policy before using the public API instance.
+//!
+//! API objects are retrieved through a [`Transport`]. By default this is [`Http`],
+//! which rate limits requests and retries them with exponential backoff when they
+//! fail with a rate limit, server error or timeout. Use [`Http::with_policy`] to
+//! configure these limits with a [`RequestPolicy`]:
+//! ```no_run
+//! # use pokerust::{Client, Http, RequestPolicy};
+//! let client = Client::new(Http::default().with_policy(RequestPolicy {
+//!     requests_per_second: Some(5),
+//!     ..Default::default()
+//! }));
+//! ```
+//! [`Memory`] serves API objects from memory, for use in tests.
+//!
+//! To work without network access, API objects can instead be read from a local
+//! copy of pokeapi's static [api-data](https://github.com/PokeAPI/api-data) dump:
+//! ```no_run
+//! # use pokerust::{Client, Mirror};
+//! let client = Client::new(Mirror::new("api-data/data/api/v2"));
+//! ```
+//!
+//! To test code that uses the API deterministically, a [`Recorder`] records every
+//! API object retrieved through another transport as a fixture, and [`Replay`]
+//! serves only those fixtures, returning [`Error::Unrecorded`] for anything else:
+//! ```no_run
+//! # use pokerust::{Client, Http, Recorder, Replay};
+//! let client = Client::new(Recorder::new(Http::default(), "fixtures"));
+//! // ... later, in tests
+//! let client = Client::new(Replay::new("fixtures"));
+//! ```
+//!
+//! The default cache directory and TTL (in seconds) can be changed by setting the
+//! `POKERUST_CACHE` and `POKERUST_CACHE_TTL` environment variables, and a mirror can
+//! be used by default by setting the `POKERUST_MIRROR` environment variable to its
+//! `data/api/v2` directory. Setting `POKERUST_RECORD` or `POKERUST_REPLAY` to a
+//! directory records fixtures to or replays fixtures from it by default.
 
 #![warn(clippy::all, clippy::pedantic, clippy::nursery)]
 
 use serde::{de::DeserializeOwned, Serialize};
 
+mod cache;
 mod client;
+mod error;
+mod fixtures;
+mod iter;
+mod localized;
+mod mirror;
 mod pokeapi;
+mod policy;
+mod transport;
 mod util;
 
+pub use cache::*;
 pub use client::*;
+pub use error::*;
+pub use fixtures::*;
+pub use iter::*;
+pub use localized::*;
+pub use mirror::*;
+pub use policy::RequestPolicy;
+pub use transport::*;
 pub use pokeapi::berries::*;
 pub use pokeapi::contests::*;
 pub use pokeapi::encounters::*;
@@ -90,7 +190,7 @@ pub use pokeapi::utility::*;
 /// Trait for API objects with an associated endpoint.
 
 pub trait Endpoint {
-    type ResourceListKind: DeserializeOwned + Serialize;
+    type ResourceListKind: DeserializeOwned + Serialize + List;
 
     const ENDPOINT: &'static str;
 }
@@ -101,6 +201,24 @@ pub trait Named {
     fn name(&self) -> &String;
 }
 
+/// Trait for `(Named)APIResources` that point to another API object.
+pub trait Resource {
+    /// The type of API object this resource points to.
+    type Target: DeserializeOwned;
+
+    /// Get the url of the API object this resource points to.
+    fn url(&self) -> &str;
+
+    /// Fetch the API object this resource points to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the API object could not be retrieved.
+    fn resolve(&self, client: &Client) -> Result<Self::Target, Error> {
+        client.get_api_loc(self.url())
+    }
+}
+
 /// Trait for API objects with an ID.
 pub trait Id {
     /// Get the ID of this object.
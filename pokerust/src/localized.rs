@@ -0,0 +1,157 @@
+use crate::{
+    AbilityFlavorText, AwesomeName, ContestName, Description, Effect, FlavorText, Genus,
+    MoveFlavorText, Name, VerboseEffect, VersionGroupFlavorText,
+};
+
+/// Trait for API objects holding text in one language, such as `Name` or `FlavorText`.
+pub trait Text {
+    /// Get the name of the language of this text, e.g. "en"
+    fn language(&self) -> &str;
+
+    /// Get the text itself
+    fn text(&self) -> &str;
+
+    /// Get the name of the version this text is for, if it is for one
+    fn version(&self) -> Option<&str> {
+        None
+    }
+
+    /// Get the name of the version group this text is for, if it is for one
+    fn version_group(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Trait for lists of the same text in different languages, such as `Vec<Name>`.
+///
+/// Languages are given as a fallback chain: the first language with any text is used.
+pub trait Localized {
+    /// The kind of text in this list
+    type Entry: Text;
+
+    /// Get the first entry matching a filter in the first of the given languages that has one.
+    fn entry_where(&self, languages: &[&str], filter: impl Fn(&Self::Entry) -> bool) -> Option<&Self::Entry>;
+
+    /// Get the entry in the first of the given languages that has one.
+    fn entry_in(&self, languages: &[&str]) -> Option<&Self::Entry> {
+        self.entry_where(languages, |_| true)
+    }
+
+    /// Get the text in the given language, e.g. "en"
+    fn localized(&self, language: &str) -> Option<&str> {
+        self.localized_in(&[language])
+    }
+
+    /// Get the text in the first of the given languages that has any.
+    fn localized_in(&self, languages: &[&str]) -> Option<&str> {
+        self.entry_in(languages).map(Text::text)
+    }
+
+    /// Get the text for a version, e.g. "firered", in the first of the given languages that has any.
+    fn localized_for_version(&self, languages: &[&str], version: &str) -> Option<&str> {
+        self.entry_where(languages, |entry| entry.version() == Some(version))
+            .map(Text::text)
+    }
+
+    /// Get the text for a version group, e.g. "firered-leafgreen", in the first of the given languages that has any.
+    fn localized_for_version_group(&self, languages: &[&str], version_group: &str) -> Option<&str> {
+        self.entry_where(languages, |entry| entry.version_group() == Some(version_group))
+            .map(Text::text)
+    }
+}
+
+impl<T: Text> Localized for [T] {
+    type Entry = T;
+
+    fn entry_where(&self, languages: &[&str], filter: impl Fn(&T) -> bool) -> Option<&T> {
+        languages.iter().find_map(|language| {
+            self.iter()
+                .find(|entry| entry.language() == *language && filter(entry))
+        })
+    }
+}
+
+macro_rules! impl_text {
+    { $A:ty, $text:ident } => {
+impl Text for $A {
+    fn language(&self) -> &str {
+        &self.language.name
+    }
+
+    fn text(&self) -> &str {
+        &self.$text
+    }
+}
+};
+    { $A:ty, $text:ident, version } => {
+impl Text for $A {
+    fn language(&self) -> &str {
+        &self.language.name
+    }
+
+    fn text(&self) -> &str {
+        &self.$text
+    }
+
+    fn version(&self) -> Option<&str> {
+        self.version.as_ref().map(|version| version.name.as_str())
+    }
+}
+};
+    { $A:ty, $text:ident, version_group } => {
+impl Text for $A {
+    fn language(&self) -> &str {
+        &self.language.name
+    }
+
+    fn text(&self) -> &str {
+        &self.$text
+    }
+
+    fn version_group(&self) -> Option<&str> {
+        Some(&self.version_group.name)
+    }
+}
+};
+}
+
+impl_text!(Name, name);
+impl_text!(ContestName, name);
+impl_text!(AwesomeName, awesome_name);
+impl_text!(Genus, genus);
+impl_text!(Description, description);
+impl_text!(Effect, effect);
+impl_text!(VerboseEffect, effect);
+impl_text!(FlavorText, flavor_text, version);
+impl_text!(MoveFlavorText, flavor_text, version_group);
+impl_text!(AbilityFlavorText, flavor_text, version_group);
+impl_text!(VersionGroupFlavorText, text, version_group);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_localized() {
+        let names: Vec<Name> = serde_json::from_str(
+            r#"[{"name":"Bisasam","language":{"name":"de","url":"https://pokeapi.co/api/v2/language/6/"}},
+            {"name":"Bulbasaur","language":{"name":"en","url":"https://pokeapi.co/api/v2/language/9/"}}]"#,
+        )
+        .unwrap();
+        assert_eq!(names.localized("en"), Some("Bulbasaur"));
+        assert_eq!(names.localized("fr"), None);
+        assert_eq!(names.localized_in(&["fr", "de", "en"]), Some("Bisasam"));
+    }
+
+    #[test]
+    fn test_localized_for_version() {
+        let entries: Vec<FlavorText> = serde_json::from_str(
+            r#"[{"flavor_text":"Red","language":{"name":"en","url":"https://pokeapi.co/api/v2/language/9/"},"version":{"name":"red","url":"https://pokeapi.co/api/v2/version/1/"}},
+            {"flavor_text":"FireRed","language":{"name":"en","url":"https://pokeapi.co/api/v2/language/9/"},"version":{"name":"firered","url":"https://pokeapi.co/api/v2/version/10/"}}]"#,
+        )
+        .unwrap();
+        assert_eq!(entries.localized("en"), Some("Red"));
+        assert_eq!(entries.localized_for_version(&["en"], "firered"), Some("FireRed"));
+        assert_eq!(entries.localized_for_version(&["en"], "emerald"), None);
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use serde_json::{json, Value};
+
+use crate::{Error, Transport};
+
+/// Local copy of the API laid out like pokeapi's static
+/// [api-data](https://github.com/PokeAPI/api-data) dump.
+///
+/// The directory is the `data/api/v2` folder of the dump, which holds one folder per
+/// endpoint, e.g. `pokemon/25/index.json` for `pokemon/25/` and `pokemon/index.json`
+/// for the complete list of pokemon. Names are resolved through the endpoint's list,
+/// and lists are paginated from the complete list.
+#[derive(Debug)]
+pub struct Mirror {
+    dir: PathBuf,
+    /// Ids of each endpoint's API objects by name, read from the endpoint's list when first needed.
+    names: Mutex<HashMap<String, HashMap<String, String>>>,
+}
+
+impl Mirror {
+    /// Use the mirror in the given directory.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            names: Mutex::default(),
+        }
+    }
+
+    /// The directory this mirror is stored in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn find(&self, loc: &str) -> Result<String, Error> {
+        let (path, query) = match loc.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (loc, None),
+        };
+        let segments = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>();
+
+        match segments.as_slice() {
+            [] => Err(not_found(loc)),
+            [endpoint] => self.list(loc, endpoint, query),
+            [endpoint, id, rest @ ..] => match self.read(loc, &segments) {
+                Err(err) if err.is_not_found() && id.parse::<i16>().is_err() => {
+                    let id = self.id_from_name(loc, endpoint, id)?;
+                    let mut segments = vec![*endpoint, id.as_str()];
+                    segments.extend(rest);
+                    self.read(loc, &segments)
+                }
+                result => result,
+            },
+        }
+    }
+
+    fn read(&self, loc: &str, segments: &[&str]) -> Result<String, Error> {
+        let mut path = self.dir.clone();
+        path.extend(segments);
+        path.push("index.json");
+        fs::read_to_string(path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => not_found(loc),
+            _ => Error::Io {
+                path: loc.to_owned(),
+                source,
+            },
+        })
+    }
+
+    /// Get a page of the complete list of an endpoint's API objects.
+    fn list(&self, loc: &str, endpoint: &str, query: Option<&str>) -> Result<String, Error> {
+        let body = self.read(loc, &[endpoint])?;
+        let mut list = serde_json::from_str::<Value>(&body).map_err(|source| Error::Decode {
+            path: loc.to_owned(),
+            source,
+        })?;
+        let Some(Value::Array(results)) = list.get_mut("results").map(Value::take) else {
+            return Err(not_found(loc));
+        };
+
+        let mut offset = 0;
+        let mut limit = 20;
+        for (key, value) in query
+            .unwrap_or_default()
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+        {
+            match key {
+                "offset" => offset = value.parse().unwrap_or(offset),
+                "limit" => limit = value.parse().unwrap_or(limit),
+                _ => (),
+            }
+        }
+
+        let count = results.len();
+        let page = |offset: usize| format!("/api/v2/{endpoint}/?offset={offset}&limit={limit}");
+        let next = (offset + limit < count).then(|| page(offset + limit));
+        let previous = (offset > 0 && limit != 0).then(|| page(offset.saturating_sub(limit)));
+        let results = results
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect::<Vec<_>>();
+
+        Ok(json!({
+            "count": count,
+            "next": next,
+            "previous": previous,
+            "results": results,
+        })
+        .to_string())
+    }
+
+    fn id_from_name(&self, loc: &str, endpoint: &str, name: &str) -> Result<String, Error> {
+        let mut names = self.names.lock().unwrap();
+        if !names.contains_key(endpoint) {
+            let body = self.read(loc, &[endpoint])?;
+            let list = serde_json::from_str::<Value>(&body).map_err(|source| Error::Decode {
+                path: loc.to_owned(),
+                source,
+            })?;
+            let ids = list["results"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry["name"].as_str()?;
+                    let url = entry["url"].as_str()?;
+                    let id = url.trim_end_matches('/').rsplit('/').next()?;
+                    Some((name.to_owned(), id.to_owned()))
+                })
+                .collect();
+            names.insert(endpoint.to_owned(), ids);
+        }
+        names[endpoint]
+            .get(name)
+            .cloned()
+            .ok_or_else(|| not_found(loc))
+    }
+}
+
+impl Transport for Mirror {
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        self.find(loc)
+    }
+}
+
+fn not_found(loc: &str) -> Error {
+    Error::NotFound {
+        path: loc.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mirror(test: &str) -> Mirror {
+        let dir = std::env::temp_dir().join(format!("pokerust-{}-{}", test, std::process::id()));
+        let write = |path: &str, body: &str| {
+            let path = dir.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, body).unwrap();
+        };
+        write(
+            "berry/index.json",
+            r#"{"count":3,"next":null,"previous":null,"results":[
+                {"name":"cheri","url":"/api/v2/berry/1/"},
+                {"name":"chesto","url":"/api/v2/berry/2/"},
+                {"name":"pecha","url":"/api/v2/berry/3/"}
+            ]}"#,
+        );
+        write("berry/2/index.json", r#"{"id":2,"name":"chesto"}"#);
+        write("berry/2/extra/index.json", "[]");
+        Mirror::new(dir)
+    }
+
+    #[test]
+    fn test_get() {
+        let mirror = mirror("test_get");
+        assert_eq!(mirror.get("berry/2/").unwrap(), r#"{"id":2,"name":"chesto"}"#);
+        assert_eq!(mirror.get("berry/chesto/").unwrap(), r#"{"id":2,"name":"chesto"}"#);
+        assert_eq!(mirror.get("berry/chesto/extra").unwrap(), "[]");
+        assert!(mirror.get("berry/1/").unwrap_err().is_not_found());
+        assert!(mirror.get("berry/oran/").unwrap_err().is_not_found());
+        assert!(mirror.get("item/1/").unwrap_err().is_not_found());
+    }
+
+    #[test]
+    fn test_list() {
+        let mirror = mirror("test_list");
+        let list = serde_json::from_str::<Value>(&mirror.get("berry/?offset=1&limit=1").unwrap()).unwrap();
+        assert_eq!(list["count"], 3);
+        assert_eq!(list["next"], "/api/v2/berry/?offset=2&limit=1");
+        assert_eq!(list["previous"], "/api/v2/berry/?offset=0&limit=1");
+        assert_eq!(list["results"][0]["name"], "chesto");
+
+        let list = serde_json::from_str::<Value>(&mirror.get("berry/?offset=0&limit=9999").unwrap()).unwrap();
+        assert_eq!(list["next"], Value::Null);
+        assert_eq!(list["previous"], Value::Null);
+        assert_eq!(list["results"].as_array().unwrap().len(), 3);
+    }
+}
@@ -108,7 +108,7 @@ pub struct PastMoveStatValues {
     pub pp: Option<u8>,
     pub effect_entries: Vec<VerboseEffect>,
     #[serde(rename = "type")]
-    type_: Option<NamedAPIResource<Type>>,
+    pub type_: Option<NamedAPIResource<Type>>,
     pub version_group: NamedAPIResource<VersionGroup>,
 }
 
@@ -206,11 +206,15 @@ pub struct Pokemon {
     pub species: NamedAPIResource<PokemonSpecies>,
     pub stats: Vec<PokemonStat>,
     pub types: Vec<PokemonType>,
+    #[serde(default)]
+    pub past_types: Vec<PokemonTypePast>,
+    #[serde(default)]
+    pub past_abilities: Vec<PokemonAbilityPast>,
 }
 
 impl Pokemon {
     /// Fetch list of `LocationAreaEncounters` from the API for this Pokemon.
-    pub fn get_encounters(&self, client: &crate::Client) -> Result<Vec<LocationAreaEncounter>, attohttpc::Error> {
+    pub fn get_encounters(&self, client: &crate::Client) -> Result<Vec<LocationAreaEncounter>, crate::Error> {
         client.get_api_loc(&self.location_area_encounters)
     }
 }
@@ -225,6 +229,27 @@ pub struct PokemonAbility {
     pub ability: NamedAPIResource<Ability>,
 }
 
+/// <https://pokeapi.co/docs/v2.html#pokemonabilitypast>
+////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PokemonAbilityPast {
+    /// The last generation the pokemon had these abilities in
+    pub generation: NamedAPIResource<Generation>,
+    pub abilities: Vec<PastPokemonAbility>,
+}
+
+/// Ability slot of a pokemon in a past generation.
+////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PastPokemonAbility {
+    pub is_hidden: bool,
+    pub slot: u8,
+    /// `None` if the pokemon did not have an ability in this slot
+    pub ability: Option<NamedAPIResource<Ability>>,
+}
+
 /// <https://pokeapi.co/docs/v2.html#pokemontype>
 //////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -235,6 +260,16 @@ pub struct PokemonType {
     pub type_: NamedAPIResource<Type>,
 }
 
+/// <https://pokeapi.co/docs/v2.html#pokemontypepast>
+////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct PokemonTypePast {
+    /// The last generation the pokemon had these types in
+    pub generation: NamedAPIResource<Generation>,
+    pub types: Vec<PokemonType>,
+}
+
 /// <https://pokeapi.co/docs/v2.html#pokemonhelditem>
 ////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -502,6 +537,8 @@ pub struct Type {
     pub id: i16,
     pub name: String,
     pub damage_relations: TypeRelations,
+    #[serde(default)]
+    pub past_damage_relations: Vec<TypeRelationsPast>,
     pub game_indices: Vec<GenerationGameIndex>,
     pub generation: NamedAPIResource<Generation>,
     pub move_damage_class: Option<NamedAPIResource<MoveDamageClass>>,
@@ -532,6 +569,16 @@ pub struct TypeRelations {
     pub double_damage_from: Vec<NamedAPIResource<Type>>,
 }
 
+/// <https://pokeapi.co/docs/v2.html#typerelationspast>
+////#[cfg_attr(debug_assertions, serde(deny_unknown_fields))]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct TypeRelationsPast {
+    /// The last generation the type had these damage relations in
+    pub generation: NamedAPIResource<Generation>,
+    pub damage_relations: TypeRelations,
+}
+
 set_endpoint!(Ability, NamedAPIResourceList, "ability");
 set_endpoint!(Characteristic, APIResourceList, "characteristic");
 set_endpoint!(EggGroup, NamedAPIResourceList, "egg-group");
@@ -30,14 +30,29 @@ pub trait List
 where
     Self: Sized,
 {
+    /// The kind of resource in this list
+    type Resource: crate::Resource;
+
     /// Get the number of items in this list
     fn count(&self) -> &u64;
 
+    /// Get the resources in this page of the list
+    fn results(&self) -> &[Self::Resource];
+
+    /// Take the resources in this page of the list
+    fn into_results(self) -> Vec<Self::Resource>;
+
+    /// Get the url of the next list, if there is one
+    fn next_url(&self) -> Option<&str>;
+
+    /// Get the url of the previous list, if there is one
+    fn previous_url(&self) -> Option<&str>;
+
     /// Get the next list
-    fn next_list(&self, client: &crate::Client) -> Result<Option<Self>, attohttpc::Error>;
+    fn next_list(&self, client: &crate::Client) -> Result<Option<Self>, crate::Error>;
 
     /// Get the previous list
-    fn previous_list(&self, client: &crate::Client) -> Result<Option<Self>, attohttpc::Error>;
+    fn previous_list(&self, client: &crate::Client) -> Result<Option<Self>, crate::Error>;
 }
 
 // impl<T> List for NamedAPIResourceList<T>
@@ -68,16 +83,34 @@ where
 // }
 
 macro_rules! impl_list {
-    { $A:tt } => {
+    { $A:tt, $R:tt } => {
 impl<T> List for $A<T>
 where
     T: DeserializeOwned,
 {
+    type Resource = $R<T>;
+
     fn count(&self) -> &u64 {
         &self.count
     }
 
-    fn next_list(&self, client: &crate::Client) -> Result<Option<Self>, attohttpc::Error> {
+    fn results(&self) -> &[Self::Resource] {
+        &self.results
+    }
+
+    fn into_results(self) -> Vec<Self::Resource> {
+        self.results
+    }
+
+    fn next_url(&self) -> Option<&str> {
+        self.next.as_deref()
+    }
+
+    fn previous_url(&self) -> Option<&str> {
+        self.previous.as_deref()
+    }
+
+    fn next_list(&self, client: &crate::Client) -> Result<Option<Self>, crate::Error> {
         if let Some(loc) = &self.next {
             let list = client.get_api_loc(loc)?;
             Ok(Some(list))
@@ -86,8 +119,8 @@ where
         }
     }
 
-    fn previous_list(&self, client: &crate::Client) -> Result<Option<Self>, attohttpc::Error> {
-        if let Some(loc) = &self.next {
+    fn previous_list(&self, client: &crate::Client) -> Result<Option<Self>, crate::Error> {
+        if let Some(loc) = &self.previous {
             let list = client.get_api_loc(loc)?;
             Ok(Some(list))
         } else {
@@ -98,5 +131,5 @@ where
 }
 }
 
-impl_list! {APIResourceList}
-impl_list! {NamedAPIResourceList}
+impl_list! {APIResourceList, APIResource}
+impl_list! {NamedAPIResourceList, NamedAPIResource}
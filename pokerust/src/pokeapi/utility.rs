@@ -1,7 +1,7 @@
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
-use crate::{impl_id_and_named, impl_named, set_endpoint, Id, Named};
+use crate::{impl_id_and_named, impl_named, set_endpoint, Id, Named, Resource};
 
 use super::encounters::*;
 use super::games::*;
@@ -49,11 +49,22 @@ impl<T> APIResource<T>
 where
     T: DeserializeOwned,
 {
-    pub fn get(&self, client: &crate::Client) -> Result<T, attohttpc::Error> {
+    pub fn get(&self, client: &crate::Client) -> Result<T, crate::Error> {
         client.get_api_loc(&self.url)
     }
 }
 
+impl<T> Resource for APIResource<T>
+where
+    T: DeserializeOwned,
+{
+    type Target = T;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+}
+
 unsafe impl<T> Send for APIResource<T> {}
 unsafe impl<T> Sync for APIResource<T> {}
 
@@ -151,11 +162,22 @@ impl<T> NamedAPIResource<T>
 where
     T: DeserializeOwned,
 {
-    pub fn get(&self, client: &crate::Client) -> Result<T, attohttpc::Error> {
+    pub fn get(&self, client: &crate::Client) -> Result<T, crate::Error> {
         client.get_api_loc(&self.url)
     }
 }
 
+impl<T> Resource for NamedAPIResource<T>
+where
+    T: DeserializeOwned,
+{
+    type Target = T;
+
+    fn url(&self) -> &str {
+        &self.url
+    }
+}
+
 unsafe impl<T> Send for NamedAPIResource<T> {}
 unsafe impl<T> Sync for NamedAPIResource<T> {}
 
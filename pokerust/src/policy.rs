@@ -0,0 +1,150 @@
+use std::{
+    sync::{Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Limits on how requests are sent to the API, and how failed requests are retried.
+///
+/// Please consult the pokeapi fair use policy before loosening these
+/// when using the public API instance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestPolicy {
+    /// The most requests started per second, or `None` for no limit.
+    pub requests_per_second: Option<u32>,
+    /// The most requests waiting on a response at once, or `None` for no limit.
+    pub max_in_flight: Option<usize>,
+    /// How long to wait for a response before the request times out.
+    pub timeout: Option<Duration>,
+    /// How many times a request is retried after a rate limit (429),
+    /// server error (5xx), timeout or dropped connection.
+    pub retries: u32,
+    /// How long to wait before the first retry. Doubles after every retry.
+    pub backoff: Duration,
+}
+
+impl RequestPolicy {
+    /// A policy that sends every request immediately and never retries.
+    #[must_use]
+    pub const fn unlimited() -> Self {
+        Self {
+            requests_per_second: None,
+            max_in_flight: None,
+            timeout: None,
+            retries: 0,
+            backoff: Duration::ZERO,
+        }
+    }
+
+    /// How long to wait before the retry following the given attempt, starting from 0.
+    #[must_use]
+    pub const fn backoff(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(2u32.saturating_pow(attempt))
+    }
+}
+
+impl Default for RequestPolicy {
+    fn default() -> Self {
+        Self {
+            requests_per_second: Some(20),
+            max_in_flight: Some(8),
+            timeout: Some(Duration::from_secs(30)),
+            retries: 4,
+            backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Shared state used to enforce a [`RequestPolicy`] across threads.
+#[derive(Debug)]
+pub struct Throttle {
+    next: Mutex<Instant>,
+    in_flight: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Throttle {
+    pub fn new() -> Self {
+        Self {
+            next: Mutex::new(Instant::now()),
+            in_flight: Mutex::new(0),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Block until a request may be sent under the given policy.
+    /// The request counts as in flight until the returned permit is dropped.
+    pub fn acquire(&self, policy: &RequestPolicy) -> Permit<'_> {
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if let Some(max) = policy.max_in_flight {
+            while *in_flight >= max.max(1) {
+                in_flight = self.released.wait(in_flight).unwrap();
+            }
+        }
+        *in_flight += 1;
+        drop(in_flight);
+
+        if let Some(rate) = policy.requests_per_second.filter(|rate| *rate != 0) {
+            let interval = Duration::from_secs(1) / rate;
+            let start = {
+                let mut next = self.next.lock().unwrap();
+                let start = (*next).max(Instant::now());
+                *next = start + interval;
+                start
+            };
+            std::thread::sleep(start.saturating_duration_since(Instant::now()));
+        }
+
+        Permit(self)
+    }
+}
+
+pub struct Permit<'a>(&'a Throttle);
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        *self.0.in_flight.lock().unwrap() -= 1;
+        self.0.released.notify_one();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        let policy = RequestPolicy {
+            backoff: Duration::from_millis(100),
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(0), Duration::from_millis(100));
+        assert_eq!(policy.backoff(3), Duration::from_millis(800));
+    }
+
+    #[test]
+    fn test_max_in_flight() {
+        let policy = RequestPolicy {
+            max_in_flight: Some(2),
+            ..RequestPolicy::unlimited()
+        };
+        let throttle = Throttle::new();
+        let peak = Mutex::new((0, 0));
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    let _permit = throttle.acquire(&policy);
+                    {
+                        let mut peak = peak.lock().unwrap();
+                        peak.0 += 1;
+                        peak.1 = peak.1.max(peak.0);
+                    }
+                    std::thread::sleep(Duration::from_millis(10));
+                    peak.lock().unwrap().0 -= 1;
+                });
+            }
+        });
+
+        assert_eq!(peak.into_inner().unwrap().1, 2);
+    }
+}
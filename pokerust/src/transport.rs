@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use attohttpc::StatusCode;
+
+use crate::{policy::Throttle, Error, RequestPolicy};
+
+/// Backend the [`Client`](crate::Client) retrieves API objects from.
+pub trait Transport: Send + Sync {
+    /// Get the body of the API object at a location relative to the API root,
+    /// e.g. "pokemon/25/" or "ability/?offset=20&limit=20".
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if there is no API object at this location.
+    fn get(&self, loc: &str) -> Result<String, Error>;
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        (**self).get(loc)
+    }
+}
+
+/// Transport that requests API objects over HTTP.
+#[derive(Debug)]
+pub struct Http {
+    /// The root of the API, e.g. <https://pokeapi.co/api/v2/>
+    pub endpoint: String,
+    /// Rate limits and retries applied to every request sent to the endpoint.
+    pub policy: RequestPolicy,
+    throttle: Throttle,
+}
+
+impl Http {
+    /// Request API objects from the given endpoint.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            policy: RequestPolicy::default(),
+            throttle: Throttle::new(),
+        }
+    }
+
+    /// Use the given rate limits and retries for requests.
+    #[must_use]
+    pub const fn with_policy(mut self, policy: RequestPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Send a single request for the body at a location relative to the endpoint.
+    fn send(&self, loc: &str) -> Result<String, Error> {
+        let transport = |source| Error::Transport {
+            path: loc.to_owned(),
+            source,
+        };
+        let mut request = attohttpc::get(format!("{}{}", self.endpoint, loc));
+        if let Some(timeout) = self.policy.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request
+            .send()
+            .map_err(transport)?;
+        match response.status() {
+            StatusCode::NOT_FOUND => Err(Error::NotFound {
+                path: loc.to_owned(),
+            }),
+            status if !status.is_success() => Err(Error::Status {
+                path: loc.to_owned(),
+                status: status.as_u16(),
+            }),
+            _ => response.text().map_err(transport),
+        }
+    }
+}
+
+impl Transport for Http {
+    /// Request the body at a location relative to the endpoint,
+    /// retrying as allowed by the request policy.
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        let mut attempt = 0;
+        loop {
+            let result = {
+                let _permit = self.throttle.acquire(&self.policy);
+                self.send(loc)
+            };
+            match result {
+                Err(err) if err.is_retryable() && attempt < self.policy.retries => {
+                    std::thread::sleep(self.policy.backoff(attempt));
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl Default for Http {
+    /// Request API objects from the endpoint in the `POKERUST_ENDPOINT` environment variable,
+    /// or the public instance at <https://pokeapi.co/api/v2/> if it is not set.
+    fn default() -> Self {
+        Self::new(match std::env::var("POKERUST_ENDPOINT") {
+            Ok(val) => val,
+            Err(std::env::VarError::NotPresent) => String::from("https://pokeapi.co/api/v2/"),
+            Err(err) => panic!("Could not read endpoint from POKERUST_ENDPOINT with error {err}"),
+        })
+    }
+}
+
+/// Transport that serves API objects from memory, for use in tests.
+///
+/// Bodies are looked up by their exact location, e.g. "pokemon/25/".
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Memory {
+    bodies: HashMap<String, String>,
+}
+
+impl Memory {
+    /// Create a transport with no API objects.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the body of the API object at a location.
+    #[must_use]
+    pub fn with(mut self, loc: impl Into<String>, body: impl Into<String>) -> Self {
+        self.insert(loc, body);
+        self
+    }
+
+    /// Add the body of the API object at a location.
+    pub fn insert(&mut self, loc: impl Into<String>, body: impl Into<String>) {
+        self.bodies.insert(loc.into(), body.into());
+    }
+}
+
+impl Transport for Memory {
+    fn get(&self, loc: &str) -> Result<String, Error> {
+        self.bodies.get(loc).cloned().ok_or_else(|| Error::NotFound {
+            path: loc.to_owned(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Berry, Client, Named};
+
+    #[test]
+    fn test_memory() {
+        let transport = Memory::new().with(
+            "berry/1/",
+            r#"{"id":1,"name":"cheri","growth_time":3,"max_harvest":5,"natural_gift_power":60,
+            "size":20,"smoothness":25,"soil_dryness":15,
+            "firmness":{"name":"soft","url":"https://pokeapi.co/api/v2/berry-firmness/2/"},
+            "flavors":[],
+            "item":{"name":"cheri-berry","url":"https://pokeapi.co/api/v2/item/126/"},
+            "natural_gift_type":{"name":"fire","url":"https://pokeapi.co/api/v2/type/10/"}}"#,
+        );
+        let client = Client::new(transport);
+
+        assert_eq!(client.get::<Berry, _>(1).unwrap().name(), "cheri");
+        assert!(client.get::<Berry, _>(2).unwrap_err().is_not_found());
+    }
+}
@@ -0,0 +1,232 @@
+use pokerust::{Id, Localized};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    report::{EntryKind, Reporter},
+    GeneratorConfig,
+};
+
+/// Name of an ability on PokeAPI, e.g. "overgrow"
+pub type AbilityId = String;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Ability {
+    pub id: AbilityId,
+    pub name: String,
+    pub effect: String,
+    /// Generation the ability was introduced in.
+    pub generation: u8,
+}
+
+/// Abilities a pokemon can have.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct AbilitySlots {
+    /// Abilities in the first and second slot.
+    pub normal: Vec<AbilityId>,
+    pub hidden: Option<AbilityId>,
+}
+
+impl AbilitySlots {
+    pub fn iter(&self) -> impl Iterator<Item = &AbilityId> {
+        self.normal.iter().chain(self.hidden.iter())
+    }
+}
+
+pub fn add_abilities(
+    pokerust: Arc<pokerust::Client>,
+    config: Arc<GeneratorConfig>,
+    reporter: Arc<Reporter>,
+    ids: Vec<AbilityId>,
+) -> Vec<Ability> {
+    pokerust
+        .get_many::<pokerust::Ability, _>(ids)
+        .into_par_iter()
+        .flat_map(|(index, ability)| get_ability(&index, ability, &config, &reporter))
+        .collect()
+}
+
+fn get_ability(
+    index: &str,
+    ability: Result<pokerust::Ability, pokerust::Error>,
+    config: &GeneratorConfig,
+    reporter: &Reporter,
+) -> Option<Ability> {
+    let ability = match ability {
+        Ok(ability) => ability,
+        Err(err) if err.is_not_found() => {
+            reporter.skipped(EntryKind::Ability, index, "It does not exist");
+            return None;
+        }
+        Err(err) => {
+            reporter.failed(EntryKind::Ability, index, format!("Could not get ability with error {}", err));
+            return None;
+        }
+    };
+
+    println!("Creating ability entry for: {}", ability.name);
+
+    let name = match ability.names.localized(&config.language) {
+        Some(name) => name.to_owned(),
+        None => {
+            reporter.failed(EntryKind::Ability, &ability.name, format!("Could not get name in {}", config.language));
+            return None;
+        }
+    };
+
+    // Effects are only written in a few languages, so fall back to the in-game text.
+    let effect = ability
+        .effect_entries
+        .localized(&config.language)
+        .or_else(|| {
+            ability
+                .flavor_text_entries
+                .localized_for_version_group(&[&config.language], &config.version_group)
+        })
+        .or_else(|| ability.flavor_text_entries.localized(&config.language))
+        .unwrap_or_default()
+        .to_owned();
+
+    Some(Ability {
+        id: ability.name,
+        name,
+        effect,
+        generation: ability.generation.id() as u8,
+    })
+}
+
+/// Generation abilities were introduced in.
+const ABILITIES_GENERATION: i16 = 3;
+/// Generation hidden abilities were introduced in.
+const HIDDEN_ABILITIES_GENERATION: i16 = 5;
+
+/// Get the ability slots a pokemon had in a generation.
+pub fn get_slots(pokemon: &pokerust::Pokemon, generation: i16) -> AbilitySlots {
+    if generation < ABILITIES_GENERATION {
+        return AbilitySlots::default();
+    }
+
+    let mut abilities = pokemon
+        .abilities
+        .iter()
+        .map(|ability| (ability.slot, (ability.is_hidden, Some(&ability.ability.name))))
+        .collect::<BTreeMap<_, _>>();
+
+    // Each past entry holds the slots that changed after its generation,
+    // so apply them from the newest to the oldest.
+    let mut past = pokemon
+        .past_abilities
+        .iter()
+        .filter(|past| past.generation.id() >= generation)
+        .collect::<Vec<_>>();
+    past.sort_by_key(|past| std::cmp::Reverse(past.generation.id()));
+
+    for past in past {
+        for ability in &past.abilities {
+            abilities.insert(
+                ability.slot,
+                (ability.is_hidden, ability.ability.as_ref().map(|ability| &ability.name)),
+            );
+        }
+    }
+
+    let mut slots = AbilitySlots::default();
+
+    for (is_hidden, ability) in abilities.into_values() {
+        match (is_hidden, ability) {
+            (_, None) => (),
+            (true, Some(ability)) => {
+                if generation >= HIDDEN_ABILITIES_GENERATION {
+                    slots.hidden = Some(ability.clone());
+                }
+            }
+            (false, Some(ability)) => slots.normal.push(ability.clone()),
+        }
+    }
+
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn ability(name: &str, id: i16, slot: u8, is_hidden: bool) -> Value {
+        json!({
+            "is_hidden": is_hidden,
+            "slot": slot,
+            "ability": { "name": name, "url": format!("https://pokeapi.co/api/v2/ability/{}/", id) },
+        })
+    }
+
+    fn pokemon(name: &str, abilities: Value, past_abilities: Value) -> pokerust::Pokemon {
+        serde_json::from_value(json!({
+            "id": 0,
+            "name": name,
+            "base_experience": 0,
+            "height": 0,
+            "is_default": true,
+            "order": 0,
+            "weight": 0,
+            "abilities": abilities,
+            "past_abilities": past_abilities,
+            "forms": [],
+            "game_indices": [],
+            "held_items": [],
+            "location_area_encounters": "",
+            "moves": [],
+            "sprites": {
+                "front_default": null,
+                "front_shiny": null,
+                "front_female": null,
+                "front_shiny_female": null,
+                "back_default": null,
+                "back_shiny": null,
+                "back_female": null,
+                "back_shiny_female": null,
+            },
+            "species": { "name": name, "url": "https://pokeapi.co/api/v2/pokemon-species/0/" },
+            "stats": [],
+            "types": [],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_past_abilities() {
+        // Gengar had Levitate until generation 6
+        let gengar = pokemon(
+            "gengar",
+            json!([ability("cursed-body", 130, 1, false)]),
+            json!([{
+                "generation": { "name": "generation-vi", "url": "https://pokeapi.co/api/v2/generation/6/" },
+                "abilities": [ability("levitate", 26, 1, false)],
+            }]),
+        );
+
+        assert_eq!(get_slots(&gengar, 3).normal, ["levitate"]);
+        assert_eq!(get_slots(&gengar, 6).normal, ["levitate"]);
+        assert_eq!(get_slots(&gengar, 7).normal, ["cursed-body"]);
+        assert_eq!(get_slots(&gengar, 2), AbilitySlots::default());
+    }
+
+    #[test]
+    fn test_hidden_ability() {
+        let bulbasaur = pokemon(
+            "bulbasaur",
+            json!([ability("overgrow", 65, 1, false), ability("chlorophyll", 34, 3, true)]),
+            json!([]),
+        );
+
+        let slots = get_slots(&bulbasaur, 3);
+        assert_eq!(slots.normal, ["overgrow"]);
+        assert_eq!(slots.hidden, None);
+
+        let slots = get_slots(&bulbasaur, 5);
+        assert_eq!(slots.normal, ["overgrow"]);
+        assert_eq!(slots.hidden.as_deref(), Some("chlorophyll"));
+    }
+}
@@ -0,0 +1,101 @@
+use std::{ops::RangeInclusive, path::PathBuf};
+
+use pokerust::{Generation, Id, Resource};
+use serde::{Deserialize, Serialize};
+
+/// Options for what [`generate_with`](crate::generate_with) produces.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GeneratorConfig {
+    /// Pokemon species to generate.
+    pub species: Selection,
+    /// Moves to generate.
+    pub moves: Selection,
+    /// Version group learnsets are taken from, e.g. "firered-leafgreen".
+    pub version_group: String,
+    /// Language names and descriptions are generated in, e.g. "en".
+    pub language: String,
+    /// Assets to download.
+    pub assets: Assets,
+    /// RON file mapping move ids to executions that replace the generated ones,
+    /// on top of the built-in overrides in `data/move_overrides.ron`.
+    pub move_overrides: Option<PathBuf>,
+    /// JSON export of Showdown's move data to read move flags from,
+    /// instead of the built-in `data/move_flags.json`.
+    pub move_flags: Option<PathBuf>,
+}
+
+impl Default for GeneratorConfig {
+    /// Every pokemon and move up to generation 3, as in FireRed and LeafGreen.
+    fn default() -> Self {
+        Self {
+            species: Selection::Generations(1..=3),
+            moves: Selection::Generations(1..=3),
+            version_group: String::from("firered-leafgreen"),
+            language: String::from("en"),
+            assets: Assets::default(),
+            move_overrides: None,
+            move_flags: None,
+        }
+    }
+}
+
+/// Selection of pokemon species or moves to generate.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum Selection {
+    /// Every entry introduced in these generations.
+    Generations(RangeInclusive<i16>),
+    /// Every entry with an id in this range.
+    Range(RangeInclusive<i16>),
+    /// Entries with these names or ids.
+    List(Vec<String>),
+}
+
+impl Selection {
+    /// Get the names or ids of the selected entries.
+    /// `entries` gets the ids of the entries introduced in a generation.
+    pub(crate) fn resolve(
+        &self,
+        pokerust: &pokerust::Client,
+        entries: impl Fn(&Generation) -> Vec<i16>,
+    ) -> Result<Vec<String>, pokerust::Error> {
+        match self {
+            Self::Generations(generations) => {
+                let mut ids = Vec::new();
+
+                for generation in pokerust.iter::<Generation>() {
+                    let generation = generation?;
+                    if !generations.contains(&generation.id()) {
+                        continue;
+                    }
+                    ids.extend(entries(&generation.resolve(pokerust)?));
+                }
+
+                ids.sort_unstable();
+                Ok(ids.into_iter().map(|id| id.to_string()).collect())
+            }
+            Self::Range(range) => Ok(range.clone().map(|id| id.to_string()).collect()),
+            Self::List(list) => Ok(list.clone()),
+        }
+    }
+}
+
+/// Which assets are downloaded alongside the generated data.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct Assets {
+    /// Front, back and icon textures of pokemon.
+    pub pokemon_textures: bool,
+    /// Cries of pokemon. These also need ffmpeg to be installed.
+    pub cries: bool,
+    /// Textures of items.
+    pub item_textures: bool,
+}
+
+impl Default for Assets {
+    fn default() -> Self {
+        Self {
+            pokemon_textures: true,
+            cries: true,
+            item_textures: true,
+        }
+    }
+}
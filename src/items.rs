@@ -2,6 +2,11 @@ use battle::pokedex::item::{Item, ItemCategory, ItemId, Price};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::Deserialize;
 
+use crate::{
+    report::{EntryKind, Reporter},
+    GeneratorConfig,
+};
+
 const ITEMS: &str = "https://raw.githubusercontent.com/pret/pokefirered/master/src/data/items.json";
 
 const ICONS: &str =
@@ -30,19 +35,19 @@ struct JsonItem {
     pocket: String,
 }
 
-pub fn add_items(pokerust: std::sync::Arc<pokerust::Client>) -> (Vec<Item>, ItemTextures) {
-    let items = || {
-        Some(
-            attohttpc::get(ITEMS)
-                .send()
-                .ok()?
-                .json::<JsonItems>()
-                .unwrap(),
-        )
+pub fn add_items(
+    pokerust: std::sync::Arc<pokerust::Client>,
+    config: std::sync::Arc<GeneratorConfig>,
+    reporter: std::sync::Arc<Reporter>,
+) -> (Vec<Item>, ItemTextures) {
+    let items = match attohttpc::get(ITEMS).send().and_then(|r| r.json::<JsonItems>()) {
+        Ok(items) => items.items,
+        Err(err) => {
+            reporter.failed(EntryKind::Item, ITEMS, format!("Could not get item list with error {}", err));
+            Vec::new()
+        }
     };
 
-    let items = (items)().map(|i| i.items).unwrap_or_default();
-
     items
         .into_par_iter()
         .flat_map(|item| {
@@ -61,7 +66,7 @@ pub fn add_items(pokerust: std::sync::Arc<pokerust::Client>) -> (Vec<Item>, Item
             let id = match idstr.parse() {
                 Ok(id) => id,
                 Err(err) => {
-                    eprintln!("Cannot parse item id {} with error {}", idstr, err);
+                    reporter.failed(EntryKind::Item, &item.name, format!("Cannot parse item id {} with error {}", idstr, err));
                     return None;
                 }
             };
@@ -85,33 +90,14 @@ pub fn add_items(pokerust: std::sync::Arc<pokerust::Client>) -> (Vec<Item>, Item
                 }
             }
 
-            let url = format!("{}/{}.png", ICONS, idstr);
-
-            let mut texture = match attohttpc::get(url).send().ok()?.bytes() {
-                Ok(bytes) => bytes,
-                Err(err) => {
-                    eprintln!("Could not get texture for item {} with error {}", name, err);
-                    return None;
-                }
+            let texture = match config.assets.item_textures {
+                true => get_texture(&pokerust, &idstr).unwrap_or_else(|err| {
+                    reporter.failed(EntryKind::Texture, &name, err);
+                    Vec::new()
+                }),
+                false => Vec::new(),
             };
 
-            if texture == "404: Not Found".as_bytes() {
-                let url = idstr.replace('_', "-");
-                match pokerust.get::<pokerust::Item, &str>(&url) {
-                    Ok(item) => match attohttpc::get(item.sprites.default).send().ok()?.bytes() {
-                        Ok(bytes) => texture = bytes,
-                        Err(err) => {
-                            eprintln!("Could not get texture for item {} with error {}", name, err);
-                            return None;
-                        }
-                    },
-                    Err(err) => {
-                        eprintln!("Cannot get item {} from PokeAPI with error {}", name, err);
-                        return None;
-                    }
-                }
-            }
-
             Some((
                 Item {
                     id,
@@ -132,3 +118,27 @@ pub fn add_items(pokerust: std::sync::Arc<pokerust::Client>) -> (Vec<Item>, Item
         })
         .unzip()
 }
+
+/// Get the icon of an item from pret, or from PokeAPI if pret does not have one.
+fn get_texture(pokerust: &pokerust::Client, idstr: &str) -> Result<Vec<u8>, String> {
+    let url = format!("{}/{}.png", ICONS, idstr);
+
+    let texture = attohttpc::get(url)
+        .send()
+        .and_then(|r| r.bytes())
+        .map_err(|err| format!("Could not get texture with error {}", err))?;
+
+    if texture != "404: Not Found".as_bytes() {
+        return Ok(texture);
+    }
+
+    let url = idstr.replace('_', "-");
+    match pokerust.get::<pokerust::Item, &str>(&url) {
+        Ok(item) => attohttpc::get(item.sprites.default)
+            .send()
+            .and_then(|r| r.bytes())
+            .map_err(|err| format!("Could not get texture with error {}", err)),
+        Err(err) if err.is_not_found() => Err(String::from("No icon on pret and it does not exist on PokeAPI")),
+        Err(err) => Err(format!("No icon on pret and cannot get item from PokeAPI with error {}", err)),
+    }
+}
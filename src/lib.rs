@@ -3,13 +3,31 @@ extern crate firecore_battle as battle;
 
 use std::sync::Arc;
 
-use battle::pokedex::{moves::Move, pokemon::Pokemon, types::PokemonType, item::Item};
+use battle::pokedex::{moves::Move, pokemon::{Pokemon, PokemonId}, types::PokemonType, item::Item};
+use hashbrown::HashMap;
 use moves::Execution;
+use pokerust::Id;
 use serde::{Deserialize, Serialize};
 
+mod abilities;
+mod config;
 mod moves;
 mod pokemon;
 mod items;
+mod report;
+mod types;
+
+pub use abilities::{Ability, AbilityId, AbilitySlots};
+pub use config::{Assets, GeneratorConfig, Selection};
+pub use pokemon::{
+    BreedingMetadata, DexEntry, EggGroup, Evolution, EvolutionTrigger, Learnset, SpeciesMetadata,
+    TrainingMetadata,
+};
+pub use report::{EntryKind, GenerateError, Report, ReportEntry};
+pub use moves::MoveFlags;
+pub use types::{Effectiveness, TypeChart};
+
+use report::Reporter;
 
 // pub(crate) const EXTENSION: &str = "ron";
 
@@ -18,18 +36,36 @@ pub struct DexGenerator {
     pub pokemon: GeneratedPokemon,
     pub moves: GeneratedMoves,
     pub items: GeneratedItems,
+    pub abilities: GeneratedAbilities,
+    /// Type chart of the configured version group's generation.
+    pub types: TypeChart,
+    /// Entries that were skipped or could not be generated.
+    pub report: Report,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GeneratedPokemon {
     pub pokemon: Vec<Pokemon>,
     pub ui_data: pokemon::PokemonOutput,
+    /// Evolutions of each pokemon into other pokemon in the dex,
+    /// of which [`Pokemon::evolution`] only holds the first.
+    pub evolutions: HashMap<PokemonId, Vec<Evolution>>,
+    /// Abilities each pokemon can have in the configured version group's generation.
+    pub abilities: HashMap<PokemonId, AbilitySlots>,
+    /// Moves each pokemon can learn in the configured version group.
+    pub learnsets: HashMap<PokemonId, Learnset>,
+    /// Catch, training and breeding data of each pokemon.
+    pub metadata: HashMap<PokemonId, SpeciesMetadata>,
+    /// Pokedex entries of each pokemon in the versions of the configured version group.
+    pub dex_entries: HashMap<PokemonId, Vec<DexEntry>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct GeneratedMoves {
     pub moves: Vec<Move>,
     pub execution: Execution,
+    /// Flags of each move, such as whether it is a sound based move.
+    pub flags: moves::Flags,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -38,38 +74,96 @@ pub struct GeneratedItems {
     pub textures: items::ItemTextures,
 }
 
-pub fn generate() -> DexGenerator {
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GeneratedAbilities {
+    /// Every ability a pokemon in the dex can have.
+    pub abilities: Vec<Ability>,
+}
+
+/// Generate the default dex, see [`GeneratorConfig::default`],
+/// retrieving API objects with the client configured by the environment, see [`pokerust::Client::default`].
+pub fn generate() -> Result<DexGenerator, GenerateError> {
+    generate_with(GeneratorConfig::default(), pokerust::Client::default())
+}
+
+/// Generate a dex with the given options, retrieving API objects with the given client,
+/// e.g. one replaying recorded fixtures.
+///
+/// Entries that could not be generated are left out and listed in [`DexGenerator::report`].
+pub fn generate_with(config: GeneratorConfig, pokerust: pokerust::Client) -> Result<DexGenerator, GenerateError> {
     // std::env::set_var("SMOL_THREADS", &std::ffi::OsString::from("10"));
 
     let start = std::time::Instant::now();
 
-    let tempdir =
-        Arc::new(tempfile::TempDir::new().unwrap_or_else(|err| {
-            panic!("Could not create temporary directory with error {}", err)
-        }));
+    let tempdir = Arc::new(tempfile::TempDir::new().map_err(GenerateError::TempDir)?);
+
+    let pokerust = Arc::new(pokerust);
+
+    let species = config.species.resolve(&pokerust, |generation| {
+        generation.pokemon_species.iter().map(Id::id).collect()
+    }).map_err(GenerateError::Selection)?;
+    let move_ids = config.moves.resolve(&pokerust, |generation| {
+        generation.moves.iter().map(Id::id).collect()
+    }).map_err(GenerateError::Selection)?;
 
-    let pokerust = Arc::new(pokerust::Client::default());
+    let move_overrides = moves::load_overrides(&config)?;
+    let version_groups = moves::VersionGroups::get(&pokerust, &config.version_group)?;
+    let move_flags = moves::MoveFlagTable::load(&config)?;
+
+    let version_group = pokerust
+        .get::<pokerust::VersionGroup, _>(&config.version_group)
+        .map_err(GenerateError::Selection)?;
+    let generation = version_group.generation.id();
+    let versions = version_group
+        .versions
+        .into_iter()
+        .map(|version| version.name)
+        .collect();
+
+    let types = types::add_types(&pokerust, generation)?;
+
+    let config = Arc::new(config);
+    let reporter = Arc::new(Reporter::default());
 
     let pokerust1 = pokerust.clone();
     let pokerust2 = pokerust.clone();
+    let pokerust3 = pokerust.clone();
+    let config1 = config.clone();
+    let config2 = config.clone();
+    let config3 = config.clone();
+    let reporter1 = reporter.clone();
+    let reporter2 = reporter.clone();
+    let reporter3 = reporter.clone();
+    let reporter4 = reporter.clone();
     
     // let client_ = client.clone();
 
-    let pokemon_thread = std::thread::spawn(|| {
-    pokemon::add_pokemon(tempdir, pokerust1)
+    let pokemon_thread = std::thread::spawn(move || {
+        pokemon::add_pokemon(tempdir, pokerust1, config1, reporter1, species, versions, generation)
     });
 
-    let moves_thread = std::thread::spawn(|| {
-        moves::add_moves(pokerust2)
+    let moves_thread = std::thread::spawn(move || {
+        moves::add_moves(pokerust2, config2, reporter2, move_overrides, version_groups, move_flags, move_ids)
     });
 
-    let items_thread = std::thread::spawn(|| {
-        items::add_items(pokerust)
+    let items_thread = std::thread::spawn(move || {
+        items::add_items(pokerust3, config3, reporter3)
     });
 
-    let (moves, battle_moves) = moves_thread.join().unwrap(); //moves_thread.join().unwrap();
+    let pokemon = pokemon_thread.join().unwrap();
+
+    let mut ability_ids = pokemon
+        .abilities
+        .values()
+        .flat_map(AbilitySlots::iter)
+        .cloned()
+        .collect::<Vec<_>>();
+    ability_ids.sort_unstable();
+    ability_ids.dedup();
+
+    let abilities = abilities::add_abilities(pokerust, config, reporter4, ability_ids);
 
-    let (pokemon, serpokemon) = pokemon_thread.join().unwrap(); //pokemon_thread.join().unwrap();
+    let moves = moves_thread.join().unwrap(); //moves_thread.join().unwrap();
 
     let (items, item_textures) = items_thread.join().unwrap();
 
@@ -77,20 +171,21 @@ pub fn generate() -> DexGenerator {
 
     println!("Finished in {} seconds!", elapsed);
 
-    DexGenerator {
-        pokemon: GeneratedPokemon {
-            pokemon,
-            ui_data: serpokemon,
-        },
-        moves: GeneratedMoves {
-            moves,
-            execution: battle_moves,
-        },
+    let report = Arc::try_unwrap(reporter)
+        .expect("Report is still being written to after generating")
+        .into_inner();
+
+    Ok(DexGenerator {
+        pokemon,
+        moves,
         items: GeneratedItems {
             items,
             textures: item_textures,
-        }
-    }
+        },
+        abilities: GeneratedAbilities { abilities },
+        types,
+        report,
+    })
 }
 
 #[inline]
@@ -98,8 +193,8 @@ pub(crate) fn capitalize_first(string: &mut String) {
     string[..1].make_ascii_uppercase();
 }
 
-pub(crate) fn type_from_id(id: i16) -> PokemonType {
-    match id {
+pub(crate) fn type_from_id(id: i16) -> Option<PokemonType> {
+    Some(match id {
         1 => PokemonType::Normal,
         10 => PokemonType::Fire,
         11 => PokemonType::Water,
@@ -118,6 +213,6 @@ pub(crate) fn type_from_id(id: i16) -> PokemonType {
         17 => PokemonType::Dark,
         9 => PokemonType::Steel,
         18 => PokemonType::Fairy,
-        _ => panic!("Could not get pokemon type from id \"{}\"", id),
-    }
+        _ => return None,
+    })
 }
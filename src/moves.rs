@@ -1,8 +1,14 @@
 use firecore_battle::pokedex::moves::MoveId;
-use pokerust::Id;
+use pokerust::{Id, Localized};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use hashbrown::HashMap;
 use std::sync::Arc;
 
+use crate::{
+    report::{EntryKind, Reporter},
+    GenerateError, GeneratedMoves, GeneratorConfig,
+};
+
 use battle::{
     default_engine::moves::{MoveExecution, MoveUse},
     moves::damage::DamageKind,
@@ -14,51 +20,222 @@ use battle::{
     pokemon::stat::BattleStatType,
 };
 
-const MOVES_SIZE: i16 = 559;
+mod flags;
+
+pub use flags::{MoveFlagTable, MoveFlags};
 
 pub type Execution = hashbrown::HashMap<MoveId, MoveExecution>;
+pub type Flags = hashbrown::HashMap<MoveId, MoveFlags>;
+
+const OVERRIDES: &str = include_str!("../data/move_overrides.ron");
+
+/// Get the built-in move overrides, and the ones in the configured file on top of them.
+pub fn load_overrides(config: &GeneratorConfig) -> Result<Execution, GenerateError> {
+    let mut overrides: Execution = ron::from_str(OVERRIDES)
+        .unwrap_or_else(|err| panic!("Could not parse built-in move overrides with error {}", err));
+
+    if let Some(path) = &config.move_overrides {
+        let file = std::fs::read_to_string(path)
+            .map_err(|err| GenerateError::MoveOverrides(path.clone(), err))?;
+        let file: Execution = ron::from_str(&file)
+            .map_err(|err| GenerateError::InvalidMoveOverrides(path.clone(), err))?;
+        overrides.extend(file);
+    }
+
+    Ok(overrides)
+}
+
+/// Order of every version group, used to roll moves back to the configured version group.
+pub struct VersionGroups {
+    orders: HashMap<String, u16>,
+    target: u16,
+    /// Generation of the configured version group.
+    generation: i16,
+}
+
+impl VersionGroups {
+    pub fn get(pokerust: &pokerust::Client, version_group: &str) -> Result<Self, GenerateError> {
+        let groups = pokerust
+            .iter::<pokerust::VersionGroup>()
+            .resolve()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(GenerateError::Selection)?;
+
+        let (target, generation) = groups
+            .iter()
+            .find(|group| group.name == version_group)
+            .map(|group| (group.order, group.generation.id()))
+            .ok_or_else(|| GenerateError::UnknownVersionGroup(version_group.to_owned()))?;
+
+        let orders = groups
+            .into_iter()
+            .map(|group| (group.name, group.order))
+            .collect();
+
+        Ok(Self {
+            orders,
+            target,
+            generation,
+        })
+    }
+
+    /// Whether a version group came out after the configured one.
+    fn is_after(&self, version_group: &str) -> bool {
+        self.orders
+            .get(version_group)
+            .map(|order| *order > self.target)
+            .unwrap_or_default()
+    }
+}
 
-pub fn add_moves(pokerust: Arc<pokerust::Client>) -> (Vec<Move>, Execution) {
-    (1..MOVES_SIZE)
+/// Use the stats a move had in the configured version group.
+///
+/// Each past value holds the stats the move had before its version group,
+/// so they are applied from the newest to the oldest version group after the configured one.
+fn roll_back(move_: &mut pokerust::Move, version_groups: &VersionGroups) {
+    let mut past_values = std::mem::take(&mut move_.past_values);
+    past_values.retain(|past| version_groups.is_after(&past.version_group.name));
+    past_values.sort_by_key(|past| std::cmp::Reverse(version_groups.orders.get(&past.version_group.name)));
+
+    for past in past_values {
+        if past.accuracy.is_some() {
+            move_.accuracy = past.accuracy;
+        }
+        if past.effect_chance.is_some() {
+            move_.effect_chance = past.effect_chance;
+        }
+        if past.power.is_some() {
+            move_.power = past.power;
+        }
+        if past.pp.is_some() {
+            move_.pp = past.pp;
+        }
+        if let Some(type_) = past.type_ {
+            move_.type_ = type_;
+        }
+    }
+}
+
+pub fn add_moves(
+    pokerust: Arc<pokerust::Client>,
+    config: Arc<GeneratorConfig>,
+    reporter: Arc<Reporter>,
+    overrides: Execution,
+    version_groups: VersionGroups,
+    flags: MoveFlagTable,
+    ids: Vec<String>,
+) -> GeneratedMoves {
+    let entries = pokerust
+        .get_many::<pokerust::Move, _>(ids)
         .into_par_iter()
-        .map(|index| get_move(index, pokerust.as_ref()))
-        .unzip()
+        .flat_map(|(index, move_)| {
+            get_move(&index, move_, &config, &reporter, &overrides, &version_groups, &flags)
+        })
+        .collect::<Vec<_>>();
+
+    let mut generated = GeneratedMoves {
+        moves: Vec::with_capacity(entries.len()),
+        execution: Execution::with_capacity(entries.len()),
+        flags: Flags::with_capacity(entries.len()),
+    };
+
+    for (move_, execution, flags) in entries {
+        generated.execution.insert(move_.id, execution);
+        generated.flags.insert(move_.id, flags);
+        generated.moves.push(move_);
+    }
+
+    generated
 }
 
-fn get_move(index: i16, client: &pokerust::Client) -> (Move, (MoveId, MoveExecution)) {
-    let mut move_ = client
-        .get::<pokerust::Move, i16>(index)
-        .unwrap_or_else(|err| {
-            eprintln!("Could not get move from id {} with error {}", index, err);
-            panic!()
-        });
+fn get_move(
+    index: &str,
+    move_: Result<pokerust::Move, pokerust::Error>,
+    config: &GeneratorConfig,
+    reporter: &Reporter,
+    overrides: &Execution,
+    version_groups: &VersionGroups,
+    flags: &MoveFlagTable,
+) -> Option<(Move, MoveExecution, MoveFlags)> {
+    let mut move_ = match move_ {
+        Ok(move_) => move_,
+        Err(err) if err.is_not_found() => {
+            reporter.skipped(EntryKind::Move, index, "It does not exist");
+            return None;
+        }
+        Err(err) => {
+            reporter.failed(EntryKind::Move, index, format!("Could not get move with error {}", err));
+            return None;
+        }
+    };
+
+    roll_back(&mut move_, version_groups);
+
+    let fail = |reason: String| {
+        reporter.failed(EntryKind::Move, &move_.name, reason);
+        None
+    };
 
-    let name = move_.names.remove(7).name;
+    let name = match move_.names.localized(&config.language) {
+        Some(name) => name.to_owned(),
+        None => return fail(format!("Could not get name in {}", config.language)),
+    };
 
-    let id = move_
-        .name
-        .parse()
-        .expect("Could not parse move name into ASCII string!");
+    let id: MoveId = match move_.name.parse() {
+        Ok(id) => id,
+        Err(_) => return fail(String::from("Could not parse move name into ASCII string")),
+    };
+
+    let pp = match move_.pp {
+        Some(pp) => pp,
+        None => return fail(String::from("Could not get PP")),
+    };
+
+    let category = match category_from_id(move_.damage_class.id()) {
+        Some(category) => category,
+        None => return fail(format!("Unknown move category {}", move_.damage_class.name)),
+    };
+
+    let pokemon_type = match crate::type_from_id(move_.type_.id()) {
+        Some(pokemon_type) => pokemon_type,
+        None => return fail(format!("Unknown type {}", move_.type_.name)),
+    };
+
+    let target = match target_from_id(move_.target.id()) {
+        Some(target) => target,
+        None => return fail(format!("Unknown move target {}", move_.target.name)),
+    };
+
+    let execution = match overrides.get(&id) {
+        Some(execution) => execution.clone(),
+        None => match get_move_execution(&move_, version_groups.generation, reporter) {
+            Ok(execution) => execution,
+            Err(err) => return fail(err),
+        },
+    };
+
+    let flags = flags.get(&move_.name).unwrap_or_else(|| {
+        reporter.failed(EntryKind::MoveEffect, &move_.name, "Missing from the move flag data, it has no flags");
+        MoveFlags::default()
+    });
 
     crate::capitalize_first(&mut move_.type_.name);
     crate::capitalize_first(&mut move_.damage_class.name);
 
     println!("Creating move entry for: {}", name);
 
-    (
+    Some((
         Move {
             id,
-            pp: move_
-                .pp
-                .unwrap_or_else(|| panic!("Could not get PP for pokemon move {}", name)),
+            pp,
             name,
-            category: category_from_id(move_.damage_class.id()),
-            pokemon_type: crate::type_from_id(move_.type_.id()),
+            category,
+            pokemon_type,
             power: move_.power,
             accuracy: move_.accuracy,
             priority: move_.priority,
-            target: target_from_id(move_.target.id()),
-            contact: false,
+            target,
+            contact: flags.contact,
             crit_rate: move_
                 .meta
                 .as_ref()
@@ -66,37 +243,53 @@ fn get_move(index: i16, client: &pokerust::Client) -> (Move, (MoveId, MoveExecut
                 .unwrap_or_default(),
             // world: is_world_move(&move_),
         },
-        (id, get_move_execution(&move_)),
-    )
+        execution,
+        flags,
+    ))
 }
 
-fn category_from_id(id: i16) -> MoveCategory {
+fn category_from_id(id: i16) -> Option<MoveCategory> {
     match id {
-        2 => MoveCategory::Physical,
-        3 => MoveCategory::Special,
-        1 => MoveCategory::Status,
-        _ => panic!("Could not get move category from id \"{}\"", id),
+        2 => Some(MoveCategory::Physical),
+        3 => Some(MoveCategory::Special),
+        1 => Some(MoveCategory::Status),
+        _ => None,
     }
 }
 
-fn get_move_execution(move_: &pokerust::Move) -> MoveExecution {
-    match move_.name.as_str() {
-        "false-swipe" => MoveExecution::Script,
-        _ => {
-            let actions = get_move_actions(move_);
-            match actions.is_empty() {
-                true => MoveExecution::None,
-                false => MoveExecution::Actions(actions),
-            }
-        }
-    }
+fn get_move_execution(
+    move_: &pokerust::Move,
+    generation: i16,
+    reporter: &Reporter,
+) -> Result<MoveExecution, String> {
+    let actions = get_move_actions(move_, generation, reporter)?;
+    Ok(match actions.is_empty() {
+        true => MoveExecution::None,
+        false => MoveExecution::Actions(actions),
+    })
 }
 
-fn get_move_actions(move_: &pokerust::Move) -> Vec<MoveUse> {
+/// Get the actions of a move in a generation.
+/// Effects the battle engine does not support are left out and added to the report.
+fn get_move_actions(
+    move_: &pokerust::Move,
+    generation: i16,
+    reporter: &Reporter,
+) -> Result<Vec<MoveUse>, String> {
     let mut usages = Vec::with_capacity(1);
 
-    if let Some(power) = move_.power {
-        usages.push(MoveUse::Damage(DamageKind::Power(power)))
+    match damage_kind(move_) {
+        Some(kind) => usages.push(MoveUse::Damage(kind)),
+        // damaging moves without power, e.g. Counter, Return and Low Kick
+        None if matches!(
+            category_from_id(move_.damage_class.id()),
+            Some(MoveCategory::Physical | MoveCategory::Special)
+        ) => reporter.failed(
+            EntryKind::MoveEffect,
+            &move_.name,
+            "Unsupported damage, it has no power",
+        ),
+        None => (),
     }
 
     // metadata
@@ -113,32 +306,53 @@ fn get_move_actions(move_: &pokerust::Move) -> Vec<MoveUse> {
             });
         }
 
-        // drain check
+        // drain and recoil check, recoil is given as negative drain
 
         if metadata.drain != 0 {
             if let Some(MoveUse::Damage(kind)) = usages.get(0) {
-                usages[0] = MoveUse::Drain(*kind, metadata.drain);
+                usages[0] = match metadata.drain > 0 {
+                    true => MoveUse::Drain(*kind, metadata.drain),
+                    false => MoveUse::Recoil(*kind, -metadata.drain),
+                };
             }
         }
 
+        // multi hit check
+
+        if let Some((min, max)) = metadata.min_hits.zip(metadata.max_hits) {
+            if let Some(MoveUse::Damage(kind)) = usages.get(0) {
+                let hits = multi_hit(*kind, min, max);
+                usages.splice(0..1, hits);
+            }
+        }
+
+        // healing check
+
+        if metadata.healing != 0 {
+            usages.push(MoveUse::Heal(metadata.healing));
+        }
+
         // status effect check
 
-        if !matches!(metadata.ailment.id(), -1 | 0) {
-            let range = status_range(metadata.min_turns, metadata.max_turns);
-
-            if let Some(ailment) = match metadata.ailment.id() {
-                1 => Some(Ailment::Paralysis),
-                2 => Some(Ailment::Sleep),
-                3 => Some(Ailment::Freeze),
-                4 => Some(Ailment::Burn),
-                5 => Some(Ailment::Poison),
-                id => {
-                    eprintln!("Could not get ailment #{}", id);
-                    None
+        match metadata.ailment.id() {
+            0 => (),
+            id => match ailment_from_id(id) {
+                Some(ailment) => {
+                    let range = status_range(&ailment, metadata.min_turns, metadata.max_turns, generation);
+                    usages.push(MoveUse::Ailment(ailment, range, metadata.ailment_chance));
                 }
-            } {
-                usages.push(MoveUse::Ailment(ailment, range, metadata.ailment_chance));
-            }
+                None => reporter.failed(
+                    EntryKind::MoveEffect,
+                    &move_.name,
+                    format!(
+                        "Unsupported ailment {} ({}% chance, {:?} to {:?} turns)",
+                        metadata.ailment.name,
+                        metadata.ailment_chance,
+                        metadata.min_turns,
+                        metadata.max_turns
+                    ),
+                ),
+            },
         }
 
         // stat stage check
@@ -147,13 +361,23 @@ fn get_move_actions(move_: &pokerust::Move) -> Vec<MoveUse> {
             let stat_changes = move_
                 .stat_changes
                 .iter()
-                .map(|stat| MoveUse::Stat(get_stat_type(stat.stat.id(), &move_.name), stat.change));
+                .map(|stat| {
+                    get_stat_type(stat.stat.id())
+                        .map(|stat_type| MoveUse::Stat(stat_type, stat.change))
+                        .ok_or_else(|| format!("Unknown battle stat type {}", stat.stat.name))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let stat_changes = match stats_affect_user(move_, &metadata.category.name) && !targets_user(move_) {
+                true => vec![MoveUse::User(stat_changes)],
+                false => stat_changes,
+            };
 
             if matches!(metadata.stat_chance, 0 | 100) {
                 usages.extend(stat_changes);
             } else {
                 usages.push(MoveUse::Chance(
-                    stat_changes.collect(),
+                    stat_changes,
                     metadata.stat_chance,
                 ));
             }
@@ -164,7 +388,26 @@ fn get_move_actions(move_: &pokerust::Move) -> Vec<MoveUse> {
     //     usages.push(MoveUse::Todo)
     // }
 
-    usages
+    Ok(usages)
+}
+
+/// Whether the stat changes of a move are meant for its user rather than its opponent.
+///
+/// Damaging moves that change the user's stats (e.g. Metal Claw, Overheat) are "damage+raise"
+/// even when the stats are lowered. Moves that only change stats are "net-good-stats",
+/// raising the user's stats (e.g. Swords Dance) or lowering the opponent's (e.g. Growl).
+fn stats_affect_user(move_: &pokerust::Move, category: &str) -> bool {
+    match category {
+        "damage+raise" => true,
+        "net-good-stats" => move_.stat_changes.iter().all(|stat| stat.change > 0),
+        _ => false,
+    }
+}
+
+/// Whether a move targets its user or the user's side, so its stat changes already apply to the user.
+/// 4 = users-field, 5 = user-or-ally, 7 = user, 13 = user-and-allies
+fn targets_user(move_: &pokerust::Move) -> bool {
+    matches!(move_.target.id(), 4 | 5 | 7 | 13)
 }
 
 // /// 15 = Cut, 19 = Fly, 57 = Surf, 70 = Strength, 127 = Waterfall, 249 = Rock Smash
@@ -175,15 +418,99 @@ fn get_move_actions(move_: &pokerust::Move) -> Vec<MoveUse> {
 //     }
 // }
 
-fn status_range(min_turns: Option<u8>, max_turns: Option<u8>) -> AilmentLength {
+/// Get how much damage a move does, including moves without power.
+fn damage_kind(move_: &pokerust::Move) -> Option<DamageKind> {
+    if let Some(power) = move_.power {
+        return Some(DamageKind::Power(power));
+    }
+
+    match move_.name.as_str() {
+        // fixed damage
+        "sonic-boom" => Some(DamageKind::Constant(20)),
+        "dragon-rage" => Some(DamageKind::Constant(40)),
+        // damage equal to the user's level
+        "seismic-toss" | "night-shade" => Some(DamageKind::Level),
+        // half of the target's current health
+        "super-fang" => Some(DamageKind::PercentCurrent(50)),
+        // one hit KO moves, e.g. Fissure and Sheer Cold
+        _ if move_.meta.as_ref().map(|meta| meta.category.name.as_str()) == Some("ohko") => {
+            Some(DamageKind::PercentMax(100))
+        }
+        _ => None,
+    }
+}
+
+/// Hit between `min` and `max` times.
+/// Moves hitting 2 to 5 times hit 2 or 3 times 3/8 of the time each, and 4 or 5 times 1/8 of the time each.
+/// Other ranges have the same chance for every number of hits.
+fn multi_hit(kind: DamageKind, min: u8, max: u8) -> Vec<MoveUse> {
+    let mut usages = vec![MoveUse::Damage(kind); min.max(1) as usize];
+
+    // chance to hit again after each hit past the minimum, innermost last
+    let chances = match (min, max) {
+        (2, 5) => vec![62, 40, 50],
+        _ => (min.max(1)..max)
+            .map(|hit| (100 * (max - hit) as u16 / (max - hit + 1) as u16) as u8)
+            .collect(),
+    };
+
+    let extra = chances
+        .into_iter()
+        .rev()
+        .fold(Vec::new(), |next, chance| {
+            let mut hit = vec![MoveUse::Damage(kind)];
+            hit.extend(next);
+            vec![MoveUse::Chance(hit, chance)]
+        });
+
+    usages.extend(extra);
+    usages
+}
+
+/// Get the ailment of a move from its PokeAPI id.
+/// Ailments without an engine equivalent, such as confusion (6), infatuation (7),
+/// trap (8), nightmare (9) and leech seed (18), give `None`, as does "unknown" (-1).
+fn ailment_from_id(id: i16) -> Option<Ailment> {
+    match id {
+        1 => Some(Ailment::Paralysis),
+        2 => Some(Ailment::Sleep),
+        3 => Some(Ailment::Freeze),
+        4 => Some(Ailment::Burn),
+        5 => Some(Ailment::Poison),
+        _ => None,
+    }
+}
+
+/// Get how long an ailment lasts in a generation, from the turns given by PokeAPI.
+/// PokeAPI leaves out the turns of sleep, so they are taken from [`sleep_turns`].
+fn status_range(
+    ailment: &Ailment,
+    min_turns: Option<u8>,
+    max_turns: Option<u8>,
+    generation: i16,
+) -> AilmentLength {
     match min_turns.zip(max_turns) {
         Some((min, max)) => AilmentLength::Temporary(min, max),
+        None if matches!(ailment, Ailment::Sleep) => {
+            let (min, max) = sleep_turns(generation);
+            AilmentLength::Temporary(min, max)
+        }
         None => AilmentLength::Permanent,
     }
 }
 
-fn get_stat_type(id: i16, name: &str) -> BattleStatType {
-    match id {
+/// Get the least and most turns sleep lasts in a generation.
+fn sleep_turns(generation: i16) -> (u8, u8) {
+    match generation {
+        ..=1 => (1, 7),
+        2 => (1, 6),
+        3 | 4 => (2, 5),
+        _ => (1, 3),
+    }
+}
+
+fn get_stat_type(id: i16) -> Option<BattleStatType> {
+    Some(match id {
         1 => BattleStatType::Basic(StatType::Health),
         2 => BattleStatType::Basic(StatType::Attack),
         3 => BattleStatType::Basic(StatType::Defense),
@@ -192,25 +519,276 @@ fn get_stat_type(id: i16, name: &str) -> BattleStatType {
         6 => BattleStatType::Basic(StatType::Speed),
         7 => BattleStatType::Accuracy,
         8 => BattleStatType::Evasion,
-        id => {
-            eprintln!("Move {} has unknown battle stat type id {}", name, id);
-            panic!()
-        }
-    }
+        _ => return None,
+    })
 }
 
-fn target_from_id(target: i16) -> MoveTarget {
-    match target {
+/// Get the target of a move from its PokeAPI id.
+fn target_from_id(target: i16) -> Option<MoveTarget> {
+    Some(match target {
+        // specific-move, e.g. Counter hits the pokemon that used the move
+        1 => MoveTarget::Opponent,
+        // selected-pokemon-me-first
+        2 => MoveTarget::Opponent,
         3 => MoveTarget::Ally,
+        // users-field, e.g. Reflect
+        4 => MoveTarget::UserAndAllies,
         5 => MoveTarget::UserOrAlly,
-        13 => MoveTarget::UserAndAllies,
+        // opponents-field, e.g. Spikes
+        6 => MoveTarget::AllOpponents,
+        7 => MoveTarget::User,
+        8 => MoveTarget::RandomOpponent,
         9 => MoveTarget::AllOtherPokemon,
+        // selected-pokemon, e.g. Tackle
         10 => MoveTarget::Any,
         11 => MoveTarget::AllOpponents,
-        15 => MoveTarget::Allies,
-        // 13 => MoveTarget::UserOrAllies,
-        7 => MoveTarget::User,
+        // entire-field, e.g. Rain Dance
+        12 => MoveTarget::AllPokemon,
+        13 => MoveTarget::UserAndAllies,
         14 => MoveTarget::AllPokemon,
-        _ => MoveTarget::None,
+        15 => MoveTarget::Allies,
+        // fainting-pokemon
+        16 => MoveTarget::None,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Build a move from the JSON PokeAPI gives for Tackle, with some of its fields replaced.
+    fn move_with(fields: serde_json::Value) -> pokerust::Move {
+        let mut move_ = json!({
+            "id": 33,
+            "name": "tackle",
+            "accuracy": 100,
+            "effect_chance": null,
+            "pp": 35,
+            "priority": 0,
+            "power": 40,
+            "contest_combos": null,
+            "contest_type": null,
+            "contest_effect": null,
+            "damage_class": { "name": "physical", "url": "https://pokeapi.co/api/v2/move-damage-class/2/" },
+            "effect_entries": [],
+            "effect_changes": [],
+            "flavor_text_entries": [],
+            "generation": { "name": "generation-i", "url": "https://pokeapi.co/api/v2/generation/1/" },
+            "machines": [],
+            "meta": null,
+            "names": [],
+            "past_values": [],
+            "stat_changes": [],
+            "super_contest_effect": null,
+            "target": { "name": "selected-pokemon", "url": "https://pokeapi.co/api/v2/move-target/10/" },
+            "type": { "name": "normal", "url": "https://pokeapi.co/api/v2/type/1/" },
+        });
+        if let (Some(move_), Some(fields)) = (move_.as_object_mut(), fields.as_object()) {
+            move_.extend(fields.clone());
+        }
+        serde_json::from_value(move_).unwrap()
+    }
+
+    fn meta(ailment: (&str, i16), category: (&str, i16)) -> serde_json::Value {
+        json!({
+            "ailment": { "name": ailment.0, "url": format!("https://pokeapi.co/api/v2/move-ailment/{}/", ailment.1) },
+            "category": { "name": category.0, "url": format!("https://pokeapi.co/api/v2/move-category/{}/", category.1) },
+            "min_hits": null,
+            "max_hits": null,
+            "min_turns": null,
+            "max_turns": null,
+            "drain": 0,
+            "healing": 0,
+            "crit_rate": 0,
+            "ailment_chance": 0,
+            "flinch_chance": 0,
+            "stat_chance": 0,
+        })
+    }
+
+    /// Get the chance to hit again after each hit past the minimum.
+    fn extra_hit_chances(usages: &[MoveUse]) -> Vec<u8> {
+        match usages.last() {
+            Some(MoveUse::Chance(hit, chance)) => {
+                assert!(matches!(hit.first(), Some(MoveUse::Damage(_))));
+                let mut chances = vec![*chance];
+                chances.extend(extra_hit_chances(hit));
+                chances
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_multi_hit() {
+        let kind = DamageKind::Power(30);
+
+        // Double Kick
+        let hits = multi_hit(kind, 2, 2);
+        assert!(matches!(hits.as_slice(), [MoveUse::Damage(_), MoveUse::Damage(_)]));
+
+        // Fury Attack
+        let hits = multi_hit(kind, 2, 5);
+        assert_eq!(hits.len(), 3);
+        assert!(matches!(hits[..2], [MoveUse::Damage(_), MoveUse::Damage(_)]));
+        assert_eq!(extra_hit_chances(&hits), [62, 40, 50]);
+
+        let hits = multi_hit(kind, 1, 3);
+        assert!(matches!(hits[0], MoveUse::Damage(_)));
+        assert_eq!(extra_hit_chances(&hits), [66, 50]);
+    }
+
+    fn version_groups(target: &str) -> VersionGroups {
+        let orders = [("red-blue", 1), ("firered-leafgreen", 7), ("black-white", 11), ("sun-moon", 17)]
+            .into_iter()
+            .map(|(name, order)| (name.to_owned(), order))
+            .collect::<HashMap<_, _>>();
+        VersionGroups {
+            target: orders[target],
+            orders,
+            generation: 3,
+        }
+    }
+
+    fn past_value(version_group: &str, accuracy: Option<u8>, power: Option<u8>) -> serde_json::Value {
+        json!({
+            "accuracy": accuracy,
+            "effect_chance": null,
+            "power": power,
+            "pp": null,
+            "effect_entries": [],
+            "type": null,
+            "version_group": { "name": version_group, "url": "https://pokeapi.co/api/v2/version-group/1/" },
+        })
+    }
+
+    #[test]
+    fn test_roll_back() {
+        // Tackle had 35 power and 95 accuracy before Black and White, and 50 power before Sun and Moon.
+        let tackle = move_with(json!({
+            "past_values": [
+                past_value("black-white", Some(95), Some(35)),
+                past_value("sun-moon", None, Some(50)),
+            ],
+        }));
+
+        let mut firered = tackle.clone();
+        roll_back(&mut firered, &version_groups("firered-leafgreen"));
+        assert_eq!((firered.power, firered.accuracy, firered.pp), (Some(35), Some(95), Some(35)));
+
+        let mut black_white = tackle.clone();
+        roll_back(&mut black_white, &version_groups("black-white"));
+        assert_eq!((black_white.power, black_white.accuracy), (Some(50), Some(100)));
+
+        let mut sun_moon = tackle;
+        roll_back(&mut sun_moon, &version_groups("sun-moon"));
+        assert_eq!((sun_moon.power, sun_moon.accuracy), (Some(40), Some(100)));
+    }
+
+    #[test]
+    fn test_unsupported_ailment() {
+        let confuse_ray = move_with(json!({
+            "name": "confuse-ray",
+            "power": null,
+            "damage_class": { "name": "status", "url": "https://pokeapi.co/api/v2/move-damage-class/1/" },
+            "meta": meta(("confusion", 6), ("ailment", 1)),
+        }));
+        let reporter = Reporter::default();
+
+        let actions = get_move_actions(&confuse_ray, 3, &reporter).unwrap();
+        let report = reporter.into_inner();
+
+        assert!(actions.is_empty());
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].kind, EntryKind::MoveEffect);
+        assert_eq!(report.failed[0].name, "confuse-ray");
+    }
+
+    #[test]
+    fn test_unsupported_damage() {
+        let reporter = Reporter::default();
+
+        let dragon_rage = move_with(json!({
+            "name": "dragon-rage",
+            "power": null,
+            "meta": meta(("none", 0), ("damage", 0)),
+        }));
+        let actions = get_move_actions(&dragon_rage, 3, &reporter).unwrap();
+        assert!(matches!(actions[..], [MoveUse::Damage(DamageKind::Constant(40))]));
+
+        let counter = move_with(json!({
+            "name": "counter",
+            "power": null,
+            "meta": meta(("none", 0), ("damage", 0)),
+        }));
+        let actions = get_move_actions(&counter, 3, &reporter).unwrap();
+        assert!(actions.is_empty());
+
+        let report = reporter.into_inner();
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].kind, EntryKind::MoveEffect);
+        assert_eq!(report.failed[0].name, "counter");
+    }
+
+    fn stat_change(change: i8, stat: (&str, i16)) -> serde_json::Value {
+        json!({ "change": change, "stat": { "name": stat.0, "url": format!("https://pokeapi.co/api/v2/stat/{}/", stat.1) } })
+    }
+
+    fn target(name: &str, id: i16) -> serde_json::Value {
+        json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/move-target/{}/", id) })
+    }
+
+    #[test]
+    fn test_stat_change_side() {
+        let reporter = Reporter::default();
+
+        let metal_claw = move_with(json!({
+            "meta": meta(("none", 0), ("damage+raise", 7)),
+            "stat_changes": [stat_change(1, ("attack", 2))],
+        }));
+        let actions = get_move_actions(&metal_claw, 3, &reporter).unwrap();
+        assert!(matches!(actions.as_slice(), [MoveUse::Damage(_), MoveUse::User(_)]));
+
+        let swords_dance = move_with(json!({
+            "power": null,
+            "target": target("user", 7),
+            "meta": meta(("none", 0), ("net-good-stats", 2)),
+            "stat_changes": [stat_change(2, ("attack", 2))],
+        }));
+        let actions = get_move_actions(&swords_dance, 3, &reporter).unwrap();
+        assert!(matches!(actions.as_slice(), [MoveUse::Stat(_, 2)]));
+
+        let growl = move_with(json!({
+            "power": null,
+            "target": target("all-opponents", 11),
+            "meta": meta(("none", 0), ("net-good-stats", 2)),
+            "stat_changes": [stat_change(-1, ("attack", 2))],
+        }));
+        let actions = get_move_actions(&growl, 3, &reporter).unwrap();
+        assert!(matches!(actions.as_slice(), [MoveUse::Stat(_, -1)]));
+    }
+
+    #[test]
+    fn test_sleep_turns() {
+        assert!(matches!(status_range(&Ailment::Sleep, None, None, 1), AilmentLength::Temporary(1, 7)));
+        assert!(matches!(status_range(&Ailment::Sleep, None, None, 3), AilmentLength::Temporary(2, 5)));
+        assert!(matches!(status_range(&Ailment::Sleep, None, None, 5), AilmentLength::Temporary(1, 3)));
+        assert!(matches!(status_range(&Ailment::Sleep, Some(1), Some(2), 3), AilmentLength::Temporary(1, 2)));
+        assert!(matches!(status_range(&Ailment::Burn, None, None, 3), AilmentLength::Permanent));
+    }
+
+    #[test]
+    fn test_target_from_id() {
+        for id in 1..=16 {
+            assert!(target_from_id(id).is_some(), "move target {} is not mapped", id);
+        }
+        assert!(matches!(target_from_id(7), Some(MoveTarget::User)));
+        assert!(matches!(target_from_id(8), Some(MoveTarget::RandomOpponent)));
+        assert!(matches!(target_from_id(10), Some(MoveTarget::Any)));
+        assert!(target_from_id(0).is_none());
+        assert!(target_from_id(17).is_none());
     }
 }
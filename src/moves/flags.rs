@@ -0,0 +1,111 @@
+use hashbrown::HashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{GenerateError, GeneratorConfig};
+
+const FLAGS: &str = include_str!("../../data/move_flags.json");
+
+/// Moves whose Showdown id is not their PokeAPI name without dashes, by PokeAPI name.
+const ALIASES: &[(&str, &str)] = &[("vice-grip", "visegrip")];
+
+/// Flags of a move that PokeAPI does not have.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct MoveFlags {
+    /// The move makes contact with the target.
+    pub contact: bool,
+    /// The move is sound based, e.g. Growl.
+    pub sound: bool,
+    /// The move is a punch, e.g. Fire Punch.
+    pub punch: bool,
+    /// The move is blocked by Protect and Detect.
+    pub protect: bool,
+    /// The move is reflected by Magic Coat.
+    pub reflectable: bool,
+    /// The move is stolen by Snatch.
+    pub snatch: bool,
+    /// The move can be copied by Mirror Move.
+    pub mirror: bool,
+}
+
+/// Move in a JSON export of Showdown's move data, e.g.
+/// `{ "firepunch": { "flags": { "contact": 1, "protect": 1, "mirror": 1, "punch": 1 } } }`
+#[derive(Deserialize)]
+struct ShowdownMove {
+    #[serde(default)]
+    flags: HashMap<String, u8>,
+}
+
+/// Flags of every move, by Showdown id.
+pub struct MoveFlagTable(HashMap<String, MoveFlags>);
+
+impl MoveFlagTable {
+    /// Read the configured move data, or the built-in one in `data/move_flags.json`
+    /// which has every move up to generation 3.
+    pub fn load(config: &GeneratorConfig) -> Result<Self, GenerateError> {
+        let moves: HashMap<String, ShowdownMove> = match &config.move_flags {
+            Some(path) => {
+                let file = std::fs::read_to_string(path)
+                    .map_err(|err| GenerateError::MoveFlags(path.clone(), err))?;
+                serde_json::from_str(&file)
+                    .map_err(|err| GenerateError::InvalidMoveFlags(path.clone(), err))?
+            }
+            None => serde_json::from_str(FLAGS)
+                .unwrap_or_else(|err| panic!("Could not parse built-in move flags with error {}", err)),
+        };
+
+        Ok(Self(
+            moves
+                .into_iter()
+                .map(|(id, move_)| {
+                    let flag = |name: &str| move_.flags.get(name).map(|v| *v != 0).unwrap_or_default();
+                    let flags = MoveFlags {
+                        contact: flag("contact"),
+                        sound: flag("sound"),
+                        punch: flag("punch"),
+                        protect: flag("protect"),
+                        reflectable: flag("reflectable"),
+                        snatch: flag("snatch"),
+                        mirror: flag("mirror"),
+                    };
+                    (id, flags)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Get the flags of a move from its name on PokeAPI, e.g. "fire-punch",
+    /// or `None` if the move is missing from the move data.
+    pub fn get(&self, name: &str) -> Option<MoveFlags> {
+        self.0.get(&showdown_id(name)).copied()
+    }
+}
+
+/// Get the Showdown id of a move from its name on PokeAPI, e.g. "fire-punch" becomes "firepunch".
+fn showdown_id(name: &str) -> String {
+    match ALIASES.iter().find(|(alias, _)| *alias == name) {
+        Some((_, id)) => String::from(*id),
+        None => name.chars().filter(char::is_ascii_alphanumeric).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_flags() {
+        let table = MoveFlagTable::load(&GeneratorConfig::default()).unwrap();
+
+        let fire_punch = table.get("fire-punch").unwrap();
+        assert!(fire_punch.contact && fire_punch.punch && fire_punch.protect && fire_punch.mirror);
+        assert!(!fire_punch.sound && !fire_punch.snatch && !fire_punch.reflectable);
+
+        let ember = table.get("ember").unwrap();
+        assert!(!ember.contact && ember.protect);
+
+        assert!(table.get("swords-dance").unwrap().snatch);
+        assert!(table.get("thunder-wave").unwrap().reflectable);
+        assert!(table.get("vice-grip").unwrap().contact);
+        assert!(table.get("not-a-move").is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use battle::pokedex::{
     pokemon::{
-        data::{Breeding, GrowthRate, LearnableMove, Training},
+        data::{Breeding, GrowthRate, Training},
         stat::{StatSet, StatType},
         Pokemon, PokemonTexture, PokemonId
     },
@@ -8,24 +8,37 @@ use battle::pokedex::{
 };
 
 use enum_map::{EnumMap, enum_map};
-use pokerust::Id;
-use hashbrown::HashMap;
+use evolution::Evolutions;
+use pokerust::{Id, Localized, Resource};
+use hashbrown::{HashMap, HashSet};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use std::path::Path;
 use std::sync::Arc;
 use tempfile::TempDir;
 
-use crate::capitalize_first;
+use crate::{
+    abilities::{self, AbilitySlots},
+    capitalize_first,
+    report::{EntryKind, Reporter},
+    GeneratedPokemon, GeneratorConfig,
+};
 
 pub type SerializedPokemon = (EnumMap<PokemonTexture, Vec<u8>>, Vec<u8>);
 pub type PokemonOutput = HashMap<PokemonId, SerializedPokemon>;
 
 mod cry;
+mod dex_entry;
+mod evolution;
 mod images;
+mod learnset;
+mod metadata;
 
-pub use images::download;
+pub use dex_entry::DexEntry;
+pub use evolution::{Evolution, EvolutionTrigger};
+pub use learnset::Learnset;
+pub use metadata::{BreedingMetadata, EggGroup, SpeciesMetadata, TrainingMetadata};
 
-const DEX_SIZE: i16 = 386;
+pub use images::download;
 
 const FRONT: &str = "front";
 const BACK: &str = "back";
@@ -40,34 +53,113 @@ const FFMPEG_PATH: &str = "ffmpeg";
 pub fn add_pokemon(
     tempdir: Arc<TempDir>,
     pokerust: Arc<pokerust::Client>,
-) -> (Vec<Pokemon>, PokemonOutput) {
-    let enable_cry = Path::new(FFMPEG_PATH).exists();
+    config: Arc<GeneratorConfig>,
+    reporter: Arc<Reporter>,
+    species: Vec<String>,
+    versions: Vec<String>,
+    generation: i16,
+) -> GeneratedPokemon {
+    let enable_cry = config.assets.cries && Path::new(FFMPEG_PATH).exists();
+
+    let in_dex = species.iter().cloned().collect::<HashSet<_>>();
+
+    let context = Context {
+        pokerust: &pokerust,
+        config: &config,
+        reporter: &reporter,
+        in_dex,
+        versions,
+        generation,
+        tempdir,
+        enable_cry,
+    };
 
-    let vec = (1..DEX_SIZE)
+    let entries = pokerust
+        .get_many::<pokerust::Pokemon, _>(species)
         .into_par_iter()
-        .map(|index| {
-            let pokerust = pokerust.clone();
-            let tempdir = tempdir.clone();
-            let (p, s) = get_pokemon(index, &pokerust, tempdir, enable_cry);
-            let s = (p.id, s);
-            (p, s)
-        })
-        .unzip();
+        .flat_map(|(index, pokemon)| get_pokemon(&index, pokemon, &context))
+        .collect::<Vec<_>>();
+
+    let mut generated = GeneratedPokemon {
+        pokemon: Vec::with_capacity(entries.len()),
+        ui_data: PokemonOutput::with_capacity(entries.len()),
+        evolutions: Evolutions::with_capacity(entries.len()),
+        abilities: HashMap::with_capacity(entries.len()),
+        learnsets: HashMap::with_capacity(entries.len()),
+        metadata: HashMap::with_capacity(entries.len()),
+        dex_entries: HashMap::with_capacity(entries.len()),
+    };
+
+    for entry in entries {
+        let id = entry.pokemon.id;
+        generated.pokemon.push(entry.pokemon);
+        generated.ui_data.insert(id, entry.ui_data);
+        generated.evolutions.insert(id, entry.evolutions);
+        generated.abilities.insert(id, entry.abilities);
+        generated.learnsets.insert(id, entry.learnset);
+        generated.metadata.insert(id, entry.metadata);
+        generated.dex_entries.insert(id, entry.dex_entries);
+    }
 
-    vec
+    generated
 }
 
-fn get_pokemon(
-    index: i16,
-    pokerust: &pokerust::Client,
+/// Data shared by every pokemon being generated.
+struct Context<'a> {
+    pokerust: &'a pokerust::Client,
+    config: &'a GeneratorConfig,
+    reporter: &'a Reporter,
+    /// Names and ids of the species in the dex.
+    in_dex: HashSet<String>,
+    /// Versions pokedex entries are taken from.
+    versions: Vec<String>,
+    /// Generation of the configured version group, pokemon get the types and abilities they had in it.
+    generation: i16,
     tempdir: Arc<TempDir>,
     enable_cry: bool,
-) -> (Pokemon, SerializedPokemon) {
+}
+
+/// Everything generated for a single pokemon.
+struct PokemonEntry {
+    pokemon: Pokemon,
+    ui_data: SerializedPokemon,
+    evolutions: Vec<Evolution>,
+    abilities: AbilitySlots,
+    learnset: Learnset,
+    metadata: SpeciesMetadata,
+    dex_entries: Vec<DexEntry>,
+}
+
+fn get_pokemon(
+    index: &str,
+    pokemon: Result<pokerust::Pokemon, pokerust::Error>,
+    context: &Context,
+) -> Option<PokemonEntry> {
+    let Context {
+        pokerust,
+        config,
+        reporter,
+        in_dex,
+        versions,
+        generation,
+        ..
+    } = context;
+
     // let before_move_check = start.elapsed().as_micros();
 
-    let pokemon: pokerust::Pokemon = pokerust
-        .get(index)
-        .unwrap_or_else(|err| panic!("Could not get pokemon at {} with error {}", index, err));
+    let pokemon = match pokemon {
+        Ok(pokemon) => pokemon,
+        Err(err) if err.is_not_found() => {
+            reporter.skipped(EntryKind::Pokemon, index, "It does not exist");
+            return None;
+        }
+        Err(err) => {
+            reporter.failed(EntryKind::Pokemon, index, format!("Could not get pokemon with error {}", err));
+            return None;
+        }
+    };
+
+    let species_name = pokemon.species.name.clone();
 
     let mut name = pokemon.name.clone();
 
@@ -94,64 +186,113 @@ fn get_pokemon(
     let name_counted = Arc::new(name_);
 
     let nc = name_counted.clone();
+    let tempdir = context.tempdir.clone();
+    let enable_cry = context.enable_cry;
 
     let cry = std::thread::spawn(move || {
         enable_cry
             .then(|| cry::get_cry(tempdir, nc))
-            .unwrap_or_default()
+            .unwrap_or(Ok(Vec::new()))
     });
 
+    let enable_textures = config.assets.pokemon_textures;
+
     let mut textures = [FRONT, BACK, ICON]
         .into_par_iter()
-        .map(move |side| download(name_counted.clone(), side))
+        .map(move |side| {
+            enable_textures
+                .then(|| download(name_counted.clone(), side))
+                .unwrap_or(Ok(Vec::new()))
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|texture| {
+            texture.unwrap_or_else(|err| {
+                reporter.failed(EntryKind::Texture, &name, err);
+                Vec::new()
+            })
+        })
         .collect::<Vec<_>>();
 
     // let after_move_check = start.elapsed().as_micros();
 
-    let primary = crate::type_from_id(pokemon.types[0].type_.id());
-    let secondary = if pokemon.types.len() == 2 {
-        Some(crate::type_from_id(pokemon.types[1].type_.id()))
-    } else {
-        None
+    let types = crate::types::past_in(&pokemon.past_types, *generation, |past| past.generation.id())
+        .map(|past| &past.types)
+        .unwrap_or(&pokemon.types);
+    let mut types = types.iter().map(|t| crate::type_from_id(t.type_.id()));
+    let (primary, secondary) = match (types.next().flatten(), types.next()) {
+        (Some(primary), None) => (primary, None),
+        (Some(primary), Some(Some(secondary))) => (primary, Some(secondary)),
+        _ => {
+            reporter.failed(EntryKind::Pokemon, &name, "Could not get its types");
+            return None;
+        }
+    };
+
+    let species: pokerust::PokemonSpecies = match pokerust.get(pokemon.species.id()) {
+        Ok(species) => species,
+        Err(err) => {
+            reporter.failed(EntryKind::Pokemon, &name, format!("Could not get species with error {}", err));
+            return None;
+        }
+    };
+    let genus = match species.genera.localized(&config.language) {
+        Some(genus) => genus,
+        None => {
+            reporter.failed(EntryKind::Pokemon, &name, format!("Could not get genus in {}", config.language));
+            return None;
+        }
     };
+    let genus = species_name(genus);
 
-    let species: pokerust::PokemonSpecies = pokerust.get(pokemon.species.id()).unwrap();
-    let genus = &species.genera[7].genus;
-    let genus = genus[0..genus.find(" ").unwrap_or(genus.len() - 1)].to_string();
+    let growth = match growth_rate_from_id(species.growth_rate.id()) {
+        Some(growth) => growth,
+        None => {
+            reporter.failed(EntryKind::Pokemon, &name, format!("Unknown growth rate {}", species.growth_rate.name));
+            return None;
+        }
+    };
 
     // Stats
 
     let stats = &pokemon.stats;
 
-    let mut moves = Vec::new();
-
-    for pmove in &pokemon.moves {
-        for version in &pmove.version_group_details {
-            if version.version_group.name.starts_with("f") && version.level_learned_at != 0 {
-                moves.push(LearnableMove(
-                    version.level_learned_at,
-                    pmove
-                        .move_
-                        .name
-                        .parse()
-                        .expect("Could not parse learnable move id!"),
-                ));
-            }
-        }
-    }
+    let ev_yield = StatSet(enum_map! {
+        StatType::Health => stats[0].effort,
+        StatType::Attack => stats[1].effort,
+        StatType::Defense => stats[2].effort,
+        StatType::SpAttack => stats[3].effort,
+        StatType::SpDefense => stats[4].effort,
+        StatType::Speed => stats[5].effort,
+    });
 
-    // let evolution: EvolutionChain = pokerust.get(species.evolution_chain.id()).await.unwrap();
+    let metadata = metadata::get_metadata(&species, ev_yield, *generation, &name, reporter);
 
-    // evolution.chain.species
+    let dex_entries = dex_entry::get_dex_entries(&species, versions, &config.language);
 
-    let cry = cry.join().unwrap();
+    let learnset = learnset::get_learnset(&pokemon.moves, &config.version_group, &name, reporter);
 
-    (
-        Pokemon {
+    let evolutions = match species.evolution_chain.resolve(pokerust) {
+        Ok(chain) => evolution::get_evolutions(&chain, &species_name, in_dex, reporter),
+        Err(err) => {
+            reporter.failed(EntryKind::Evolution, &name, format!("Could not get evolution chain with error {}", err));
+            Vec::new()
+        }
+    };
+
+    let cry = cry.join().unwrap().unwrap_or_else(|err| {
+        reporter.failed(EntryKind::Cry, &name, err);
+        Vec::new()
+    });
+
+    let abilities = abilities::get_slots(&pokemon, *generation);
+
+    Some(PokemonEntry {
+        pokemon: Pokemon {
             id: pokemon.id as u16,
             name,
             types: Types { primary, secondary },
-            moves,
+            moves: learnset.learnable_moves(),
             base: StatSet(enum_map! {
                 StatType::Health => stats[0].base_stat,
                 StatType::Attack => stats[1].base_stat,
@@ -161,12 +302,13 @@ fn get_pokemon(
                 StatType::Speed => stats[5].base_stat,
             }),
             species: genus,
-            evolution: None,
+            // the first evolution, every evolution and its trigger is in GeneratedPokemon::evolutions
+            evolution: evolutions.first().map(|evolution| evolution.species),
             height: pokemon.height,
             weight: pokemon.weight,
             training: Training {
                 base_exp: pokemon.base_experience,
-                growth: growth_rate_from_id(species.growth_rate.id()),
+                growth,
             },
             breeding: Breeding {
                 gender: match species.gender_rate {
@@ -175,21 +317,264 @@ fn get_pokemon(
                 },
             },
         },
-        (
+        ui_data: (
             EnumMap::from_array([textures.remove(0), textures.remove(0), textures.remove(0)]),
             cry,
         ),
-    )
+        evolutions,
+        abilities,
+        learnset,
+        metadata,
+        dex_entries,
+    })
+}
+
+/// Get the species of a pokemon from its localized genus, leaving out the word "Pokémon",
+/// e.g. "Seed Pokémon" and "Pokémon Graine" become "Seed" and "Graine".
+/// Genera that are not split into words, e.g. "たねポケモン", are kept whole.
+fn species_name(genus: &str) -> String {
+    let words = genus
+        .split_whitespace()
+        .filter(|word| *word != "Pokémon")
+        .collect::<Vec<_>>();
+    match words.is_empty() {
+        true => genus.to_owned(),
+        false => words.join(" "),
+    }
 }
 
-pub fn growth_rate_from_id(id: i16) -> GrowthRate {
-    match id {
+pub fn growth_rate_from_id(id: i16) -> Option<GrowthRate> {
+    Some(match id {
         1 => GrowthRate::Slow,
         2 => GrowthRate::Medium,
         3 => GrowthRate::Fast,
         4 => GrowthRate::MediumSlow,
         6 => GrowthRate::FastThenVerySlow,
         5 => GrowthRate::SlowThenVeryFast,
-        _ => panic!("Could not get growth rate from id \"{}\"", id),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use battle::pokedex::types::PokemonType;
+    use pokerust::Memory;
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::Assets;
+
+    fn resource(endpoint: &str, name: &str, id: i16) -> Value {
+        json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/{}/{}/", endpoint, id) })
+    }
+
+    fn stat(name: &str, id: i16, base_stat: u8, effort: u8) -> Value {
+        json!({ "stat": resource("stat", name, id), "base_stat": base_stat, "effort": effort })
+    }
+
+    /// Bulbasaur as PokeAPI gives it, with only its FireRed data.
+    fn bulbasaur() -> pokerust::Pokemon {
+        serde_json::from_value(json!({
+            "id": 1,
+            "name": "bulbasaur",
+            "base_experience": 64,
+            "height": 7,
+            "is_default": true,
+            "order": 1,
+            "weight": 69,
+            "abilities": [
+                { "is_hidden": false, "slot": 1, "ability": resource("ability", "overgrow", 65) },
+                { "is_hidden": true, "slot": 3, "ability": resource("ability", "chlorophyll", 34) },
+            ],
+            "forms": [],
+            "game_indices": [],
+            "held_items": [],
+            "location_area_encounters": "https://pokeapi.co/api/v2/pokemon/1/encounters",
+            "moves": [{
+                "move": resource("move", "tackle", 33),
+                "version_group_details": [
+                    {
+                        "level_learned_at": 1,
+                        "move_learn_method": resource("move-learn-method", "level-up", 1),
+                        "version_group": resource("version-group", "firered-leafgreen", 7),
+                    },
+                    {
+                        "level_learned_at": 1,
+                        "move_learn_method": resource("move-learn-method", "level-up", 1),
+                        "version_group": resource("version-group", "sword-shield", 20),
+                    },
+                ],
+            }],
+            "sprites": {
+                "front_default": null,
+                "front_shiny": null,
+                "front_female": null,
+                "front_shiny_female": null,
+                "back_default": null,
+                "back_shiny": null,
+                "back_female": null,
+                "back_shiny_female": null,
+            },
+            "species": resource("pokemon-species", "bulbasaur", 1),
+            "stats": [
+                stat("hp", 1, 45, 0),
+                stat("attack", 2, 49, 0),
+                stat("defense", 3, 49, 0),
+                stat("special-attack", 4, 65, 1),
+                stat("special-defense", 5, 65, 0),
+                stat("speed", 6, 45, 0),
+            ],
+            "types": [
+                { "slot": 1, "type": resource("type", "grass", 12) },
+                { "slot": 2, "type": resource("type", "poison", 4) },
+            ],
+        }))
+        .unwrap()
+    }
+
+    fn bulbasaur_species() -> Value {
+        json!({
+            "id": 1,
+            "name": "bulbasaur",
+            "order": 1,
+            "gender_rate": 1,
+            "capture_rate": 45,
+            "base_happiness": 50,
+            "is_baby": false,
+            "hatch_counter": 20,
+            "has_gender_differences": false,
+            "forms_switchable": false,
+            "growth_rate": resource("growth-rate", "medium-slow", 4),
+            "pokedex_numbers": [],
+            "egg_groups": [resource("egg-group", "monster", 1), resource("egg-group", "plant", 7)],
+            "color": resource("pokemon-color", "green", 5),
+            "shape": resource("pokemon-shape", "quadruped", 8),
+            "evolves_from_species": null,
+            "evolution_chain": { "url": "https://pokeapi.co/api/v2/evolution-chain/1/" },
+            "habitat": resource("pokemon-habitat", "grassland", 3),
+            "generation": resource("generation", "generation-i", 1),
+            "names": [],
+            "pal_park_encounters": [],
+            "flavor_text_entries": [
+                {
+                    "flavor_text": "There is a plant seed on its back right\nfrom the day this POKéMON is born.\nThe seed slowly grows larger.",
+                    "language": resource("language", "en", 9),
+                    "version": resource("version", "firered", 10),
+                },
+                {
+                    "flavor_text": "Bulbasaur porte une graine sur son dos depuis sa naissance.",
+                    "language": resource("language", "fr", 5),
+                    "version": resource("version", "firered", 10),
+                },
+            ],
+            "form_descriptions": [],
+            "genera": [
+                { "genus": "Seed Pokémon", "language": resource("language", "en", 9) },
+                { "genus": "Pokémon Graine", "language": resource("language", "fr", 5) },
+            ],
+            "varieties": [],
+        })
+    }
+
+    fn evolution_detail(trigger: (&str, i16), min_level: u8) -> Value {
+        json!({
+            "item": null,
+            "trigger": resource("evolution-trigger", trigger.0, trigger.1),
+            "gender": null,
+            "held_item": null,
+            "known_move": null,
+            "known_move_type": null,
+            "location": null,
+            "min_level": min_level,
+            "min_happiness": null,
+            "min_beauty": null,
+            "min_affection": null,
+            "needs_overworld_rain": false,
+            "party_species": null,
+            "party_type": null,
+            "relative_physical_stats": null,
+            "time_of_day": "",
+            "trade_species": null,
+            "turn_upside_down": false,
+        })
+    }
+
+    fn bulbasaur_chain() -> Value {
+        json!({
+            "id": 1,
+            "baby_trigger_item": null,
+            "chain": {
+                "is_baby": false,
+                "species": resource("pokemon-species", "bulbasaur", 1),
+                "evolution_details": [],
+                "evolves_to": [{
+                    "is_baby": false,
+                    "species": resource("pokemon-species", "ivysaur", 2),
+                    "evolution_details": [evolution_detail(("level-up", 1), 16)],
+                    "evolves_to": [{
+                        "is_baby": false,
+                        "species": resource("pokemon-species", "venusaur", 3),
+                        "evolution_details": [evolution_detail(("level-up", 1), 32)],
+                        "evolves_to": [],
+                    }],
+                }],
+            },
+        })
+    }
+
+    #[test]
+    fn test_get_pokemon() {
+        let pokerust = pokerust::Client::new(
+            Memory::new()
+                .with("pokemon-species/1/", bulbasaur_species().to_string())
+                .with("evolution-chain/1/", bulbasaur_chain().to_string()),
+        );
+        let config = GeneratorConfig {
+            assets: Assets {
+                pokemon_textures: false,
+                cries: false,
+                item_textures: false,
+            },
+            ..Default::default()
+        };
+        let reporter = Reporter::default();
+        let context = Context {
+            pokerust: &pokerust,
+            config: &config,
+            reporter: &reporter,
+            in_dex: ["1", "2"].into_iter().map(String::from).collect(),
+            versions: vec![String::from("firered"), String::from("leafgreen")],
+            generation: 3,
+            tempdir: Arc::new(TempDir::new().unwrap()),
+            enable_cry: false,
+        };
+
+        let entry = get_pokemon("1", Ok(bulbasaur()), &context).unwrap();
+
+        assert_eq!(entry.pokemon.id, 1);
+        assert_eq!(entry.pokemon.name, "Bulbasaur");
+        assert_eq!(entry.pokemon.species, "Seed");
+        assert_eq!(entry.pokemon.types.primary, PokemonType::Grass);
+        assert_eq!(entry.pokemon.types.secondary, Some(PokemonType::Poison));
+        assert_eq!(entry.pokemon.base.0[StatType::SpAttack], 65);
+        assert_eq!(entry.pokemon.evolution, Some(2));
+        assert_eq!(entry.evolutions.len(), 1);
+        assert_eq!(entry.learnset.level_up.len(), 1);
+        assert_eq!(entry.metadata.training.ev_yield.0[StatType::SpAttack], 1);
+        assert_eq!(entry.metadata.breeding.hatch_steps, 5376);
+        assert_eq!(entry.dex_entries.len(), 1);
+        assert_eq!(
+            entry.dex_entries[0].text,
+            "There is a plant seed on its back right from the day this POKéMON is born. The seed slowly grows larger."
+        );
+        assert!(reporter.into_inner().failed.is_empty());
+    }
+
+    #[test]
+    fn test_species_name() {
+        assert_eq!(species_name("Seed Pokémon"), "Seed");
+        assert_eq!(species_name("Pokémon Graine"), "Graine");
+        assert_eq!(species_name("Samen-Pokémon"), "Samen-Pokémon");
+        assert_eq!(species_name("たねポケモン"), "たねポケモン");
     }
 }
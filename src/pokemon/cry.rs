@@ -8,34 +8,40 @@ use std::process::Stdio;
 
 use tempfile::TempDir;
 
-pub fn get_cry(tempdir: Arc<TempDir>, pokemon: Arc<String>) -> Vec<u8> {
+pub fn get_cry(tempdir: Arc<TempDir>, pokemon: Arc<String>) -> Result<Vec<u8>, String> {
     let pokemon = if &**pokemon == "unown/e" {
         Arc::new("unown".to_string())
     } else {
         pokemon
     };
-    let response = attohttpc::get(&format!("https://raw.githubusercontent.com/pret/pokefirered/master/sound/direct_sound_samples/cry_{}.aif", pokemon)).send().unwrap_or_else(|err| panic!("Could not get web response for cry of {}. Error: {}", pokemon, err));
+    let response = attohttpc::get(&format!("https://raw.githubusercontent.com/pret/pokefirered/master/sound/direct_sound_samples/cry_{}.aif", pokemon)).send().map_err(|err| format!("Could not get web response for cry of {}. Error: {}", pokemon, err))?;
+    if !response.is_success() {
+        return Err(format!("Could not get cry of {} with status {}", pokemon, response.status()));
+    }
     let bytes = response
         .bytes()
-        .unwrap_or_else(|err| panic!("Could not get cry bytes for {} with error {}", pokemon, err));
+        .map_err(|err| format!("Could not get cry bytes for {} with error {}", pokemon, err))?;
     let temp_path = tempdir.path();
     let temp_cry = temp_path.join(format!("{}-temp_cry.aif", pokemon));
     let cry = temp_path.join(format!("{}-cry.ogg", pokemon));
-    fs::write(&temp_cry, &bytes).unwrap_or_else(|err| {
-        panic!(
+    fs::write(&temp_cry, &bytes).map_err(|err| {
+        format!(
             "Could not write temporary cry file at {:?} for {} with error {}",
             &temp_cry, pokemon, err
         )
-    });
+    })?;
     let mut command = process::Command::new(super::FFMPEG_PATH);
     command.stdout(Stdio::null()); //.kill_on_drop(true);
     command.arg("-i").arg(&temp_cry).arg(&cry);
-    command.output().unwrap_or_else(|err| {
-        panic!(
+    let output = command.output().map_err(|err| {
+        format!(
             "Could not execute ffmpeg for {} with error {}",
             pokemon, err
         )
-    });
+    })?;
+    if !output.status.success() {
+        return Err(format!("ffmpeg could not convert cry for {} with {}", pokemon, output.status));
+    }
     fs::read(cry)
-        .unwrap_or_else(|err| panic!("Could not read cry file for {} with error {}", pokemon, err))
+        .map_err(|err| format!("Could not read cry file for {} with error {}", pokemon, err))
 }
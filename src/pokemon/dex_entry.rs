@@ -0,0 +1,62 @@
+use pokerust::Localized;
+use serde::{Deserialize, Serialize};
+
+/// Pokedex entry text of a pokemon in a version.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DexEntry {
+    /// Name of the version, e.g. "firered"
+    pub version: String,
+    pub text: String,
+}
+
+/// Get the pokedex entries of a species in each of the given versions that has one.
+pub fn get_dex_entries(
+    species: &pokerust::PokemonSpecies,
+    versions: &[String],
+    language: &str,
+) -> Vec<DexEntry> {
+    versions
+        .iter()
+        .flat_map(|version| {
+            species
+                .flavor_text_entries
+                .localized_for_version(&[language], version)
+                .map(|text| DexEntry {
+                    version: version.clone(),
+                    text: normalize(text),
+                })
+        })
+        .collect()
+}
+
+/// Join the lines of text taken from the games' text boxes.
+///
+/// PokeAPI keeps the line breaks (`\n`) and page breaks (`\f`) of the games,
+/// and a soft hyphen (`\u{ad}`) where a word was split over two lines.
+fn normalize(text: &str) -> String {
+    text.replace("\u{ad}\n", "")
+        .replace("-\n", "-")
+        .split(|c: char| c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        // Ivysaur in FireRed, with a page break
+        assert_eq!(
+            normalize("To support its bulb, IVYSAUR's legs\ngrow sturdy. If it spends more time lying in\nthe sunlight, the bud will soon bloom into\fa large flower."),
+            "To support its bulb, IVYSAUR's legs grow sturdy. If it spends more time lying in the sunlight, the bud will soon bloom into a large flower."
+        );
+        // a word split over two lines with a soft hyphen, and a hyphenated word split after its hyphen
+        assert_eq!(
+            normalize("It stores electricity in the electric sacs\non its cheeks. When it releases pent-\nup energy in a burst, the elec\u{ad}\ntric power is equal to a lightning bolt."),
+            "It stores electricity in the electric sacs on its cheeks. When it releases pent-up energy in a burst, the electric power is equal to a lightning bolt."
+        );
+    }
+}
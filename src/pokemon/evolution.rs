@@ -0,0 +1,216 @@
+use battle::pokedex::{item::ItemId, pokemon::PokemonId};
+use hashbrown::{HashMap, HashSet};
+use pokerust::{ChainLink, EvolutionChain, EvolutionDetail, Id};
+use serde::{Deserialize, Serialize};
+
+use crate::report::{EntryKind, Reporter};
+
+pub type Evolutions = HashMap<PokemonId, Vec<Evolution>>;
+
+/// How a pokemon evolves into another pokemon.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Evolution {
+    /// The pokemon this evolves into.
+    pub species: PokemonId,
+    pub trigger: EvolutionTrigger,
+    /// Item the pokemon has to hold for the trigger to work.
+    pub held_item: Option<ItemId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EvolutionTrigger {
+    /// Level up to at least this level.
+    Level(u8),
+    /// Level up with at least this much happiness.
+    Happiness(u8),
+    /// Use this item on the pokemon.
+    Item(ItemId),
+    /// Trade the pokemon.
+    Trade,
+}
+
+/// Get the evolutions of a species into species in the dex.
+///
+/// `in_dex` holds the names and ids of the species in the dex.
+pub fn get_evolutions(
+    chain: &EvolutionChain,
+    species: &str,
+    in_dex: &HashSet<String>,
+    reporter: &Reporter,
+) -> Vec<Evolution> {
+    let link = match find_link(&chain.chain, species) {
+        Some(link) => link,
+        None => return Vec::new(),
+    };
+
+    let mut evolutions = Vec::new();
+
+    for into in &link.evolves_to {
+        let id = into.species.id();
+        if !in_dex.contains(&into.species.name) && !in_dex.contains(&id.to_string()) {
+            continue;
+        }
+        for detail in &into.evolution_details {
+            match get_evolution(id as _, detail) {
+                Ok(evolution) => evolutions.push(evolution),
+                Err(err) => reporter.failed(
+                    EntryKind::Evolution,
+                    species,
+                    format!("Could not evolve into {}: {}", into.species.name, err),
+                ),
+            }
+        }
+    }
+
+    evolutions
+}
+
+fn find_link<'a>(link: &'a ChainLink, species: &str) -> Option<&'a ChainLink> {
+    match link.species.name == species {
+        true => Some(link),
+        false => link
+            .evolves_to
+            .iter()
+            .find_map(|link| find_link(link, species)),
+    }
+}
+
+fn get_evolution(species: PokemonId, detail: &EvolutionDetail) -> Result<Evolution, String> {
+    let conditions = unsupported_conditions(detail);
+    if !conditions.is_empty() {
+        return Err(format!("Unsupported conditions {}", conditions.join(", ")));
+    }
+
+    let held_item = detail.held_item.as_ref().map(|item| item_id(&item.name)).transpose()?;
+
+    let trigger = match detail.trigger.name.as_str() {
+        "level-up" => match (detail.min_level, detail.min_happiness) {
+            (_, Some(happiness)) => EvolutionTrigger::Happiness(happiness),
+            (Some(level), None) => EvolutionTrigger::Level(level),
+            (None, None) => return Err(String::from("Unsupported level up condition")),
+        },
+        "use-item" => match &detail.item {
+            Some(item) => EvolutionTrigger::Item(item_id(&item.name)?),
+            None => return Err(String::from("Missing item to use")),
+        },
+        "trade" => EvolutionTrigger::Trade,
+        trigger => return Err(format!("Unsupported trigger {}", trigger)),
+    };
+
+    Ok(Evolution {
+        species,
+        trigger,
+        held_item,
+    })
+}
+
+/// Get the names of the conditions of an evolution that [`Evolution`] cannot hold.
+fn unsupported_conditions(detail: &EvolutionDetail) -> Vec<&'static str> {
+    [
+        ("gender", detail.gender.is_some()),
+        ("known_move", detail.known_move.is_some()),
+        ("known_move_type", detail.known_move_type.is_some()),
+        ("location", detail.location.is_some()),
+        ("min_beauty", detail.min_beauty.is_some()),
+        ("min_affection", detail.min_affection.is_some()),
+        ("needs_overworld_rain", detail.needs_overworld_rain),
+        ("party_species", detail.party_species.is_some()),
+        ("party_type", detail.party_type.is_some()),
+        ("relative_physical_stats", detail.relative_physical_stats.is_some()),
+        ("time_of_day", !detail.time_of_day.is_empty()),
+        ("trade_species", detail.trade_species.is_some()),
+        ("turn_upside_down", detail.turn_upside_down),
+    ]
+    .into_iter()
+    .filter(|(_, present)| *present)
+    .map(|(name, _)| name)
+    .collect()
+}
+
+/// Get the id of an item from its name on PokeAPI, e.g. "fire-stone" becomes "fire_stone".
+fn item_id(name: &str) -> Result<ItemId, String> {
+    name.replace('-', "_")
+        .parse()
+        .map_err(|_| format!("Could not parse item id {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn resource(endpoint: &str, name: &str, id: i16) -> Value {
+        json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/{}/{}/", endpoint, id) })
+    }
+
+    /// Build an evolution detail with the given trigger and some of its fields set.
+    fn detail(trigger: (&str, i16), fields: Value) -> Value {
+        let mut detail = json!({
+            "item": null,
+            "trigger": resource("evolution-trigger", trigger.0, trigger.1),
+            "gender": null,
+            "held_item": null,
+            "known_move": null,
+            "known_move_type": null,
+            "location": null,
+            "min_level": null,
+            "min_happiness": null,
+            "min_beauty": null,
+            "min_affection": null,
+            "needs_overworld_rain": false,
+            "party_species": null,
+            "party_type": null,
+            "relative_physical_stats": null,
+            "time_of_day": "",
+            "trade_species": null,
+            "turn_upside_down": false,
+        });
+        if let (Some(detail), Some(fields)) = (detail.as_object_mut(), fields.as_object()) {
+            detail.extend(fields.clone());
+        }
+        detail
+    }
+
+    fn link(species: (&str, i16), details: Value, evolves_to: Value) -> Value {
+        json!({
+            "is_baby": false,
+            "species": resource("pokemon-species", species.0, species.1),
+            "evolution_details": details,
+            "evolves_to": evolves_to,
+        })
+    }
+
+    #[test]
+    fn test_eevee() {
+        let chain: EvolutionChain = serde_json::from_value(json!({
+            "id": 67,
+            "baby_trigger_item": null,
+            "chain": link(("eevee", 133), json!([]), json!([
+                link(("vaporeon", 134), json!([detail(("use-item", 3), json!({ "item": resource("item", "water-stone", 84) }))]), json!([])),
+                link(("espeon", 196), json!([detail(("level-up", 1), json!({ "min_happiness": 160, "time_of_day": "day" }))]), json!([])),
+                link(("leafeon", 470), json!([detail(("use-item", 3), json!({ "item": resource("item", "leaf-stone", 85) }))]), json!([])),
+            ])),
+        }))
+        .unwrap();
+        let in_dex = ["133", "134", "196"].into_iter().map(String::from).collect();
+        let reporter = Reporter::default();
+
+        let evolutions = get_evolutions(&chain, "eevee", &in_dex, &reporter);
+        let report = reporter.into_inner();
+
+        // Leafeon is not in the dex, so it is left out without being reported
+        assert_eq!(
+            evolutions,
+            [Evolution {
+                species: 134,
+                trigger: EvolutionTrigger::Item("water_stone".parse().unwrap()),
+                held_item: None,
+            }]
+        );
+        assert_eq!(report.failed.len(), 1);
+        assert_eq!(report.failed[0].kind, EntryKind::Evolution);
+        assert_eq!(report.failed[0].name, "eevee");
+        assert!(report.failed[0].reason.contains("time_of_day"));
+    }
+}
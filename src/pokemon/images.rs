@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use image::{DynamicImage, GenericImageView, Pixel};
 
-pub fn download(pokemon: Arc<String>, side: &str) -> Vec<u8> {
+pub fn download(pokemon: Arc<String>, side: &str) -> Result<Vec<u8>, String> {
     let pokemon = if &**pokemon == "castform" && side != super::ICON {
         Arc::new("castform/normal".to_owned())
     } else {
@@ -12,18 +12,20 @@ pub fn download(pokemon: Arc<String>, side: &str) -> Vec<u8> {
         "https://raw.githubusercontent.com/pret/pokefirered/master/graphics/pokemon/{}/{}.png",
         pokemon, side
     )).send()
-    .unwrap_or_else(|err| panic!("Cannot get icon with error {}", err));
-    let bytes = response.bytes().unwrap();
+    .map_err(|err| format!("Cannot get {} image for {} with error {}", side, pokemon, err))?;
+    let bytes = response
+        .bytes()
+        .map_err(|err| format!("Cannot get {} image bytes for {} with error {}", side, pokemon, err))?;
     let mut image = image::load_from_memory_with_format(&bytes, image::ImageFormat::Png)
-        .unwrap_or_else(|err| {
-            panic!(
+        .map_err(|err| {
+            format!(
                 "Could not get {} image for {} with error {}",
                 side, pokemon, err
             )
-        });
+        })?;
     let (top, bottom) = get_heights(&image);
     image = image.crop(0, top, image.width(), bottom - top + 1);
-    image.into_rgba8().into_raw()
+    Ok(image.into_rgba8().into_raw())
 }
 
 fn get_heights(image: &DynamicImage) -> (u32, u32) {
@@ -0,0 +1,73 @@
+use battle::pokedex::{moves::MoveId, pokemon::data::LearnableMove};
+use serde::{Deserialize, Serialize};
+
+use crate::report::{EntryKind, Reporter};
+
+/// Moves a pokemon can learn in a version group, by how they are learned.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Learnset {
+    /// Moves learned when reaching a level, as (level, move).
+    pub level_up: Vec<(u8, MoveId)>,
+    /// Moves taught by TMs and HMs.
+    pub machine: Vec<MoveId>,
+    /// Moves taught by move tutors.
+    pub tutor: Vec<MoveId>,
+    /// Moves the pokemon can hatch knowing.
+    pub egg: Vec<MoveId>,
+}
+
+impl Learnset {
+    /// Get the moves learned by leveling up, as used by the battle engine.
+    pub fn learnable_moves(&self) -> Vec<LearnableMove> {
+        self.level_up
+            .iter()
+            .map(|(level, id)| LearnableMove(*level, id.clone()))
+            .collect()
+    }
+}
+
+/// Get the learnset of a pokemon in a version group, e.g. "firered-leafgreen".
+pub fn get_learnset(
+    moves: &[pokerust::PokemonMove],
+    version_group: &str,
+    name: &str,
+    reporter: &Reporter,
+) -> Learnset {
+    let mut learnset = Learnset::default();
+
+    for pmove in moves {
+        for version in &pmove.version_group_details {
+            if version.version_group.name != version_group {
+                continue;
+            }
+
+            let id = match pmove.move_.name.parse() {
+                Ok(id) => id,
+                Err(_) => {
+                    reporter.failed(
+                        EntryKind::Pokemon,
+                        name,
+                        format!("Could not parse learnable move id {}", pmove.move_.name),
+                    );
+                    continue;
+                }
+            };
+
+            match version.move_learn_method.name.as_str() {
+                "level-up" => learnset.level_up.push((version.level_learned_at, id)),
+                "machine" => learnset.machine.push(id),
+                "tutor" => learnset.tutor.push(id),
+                "egg" => learnset.egg.push(id),
+                method => reporter.failed(
+                    EntryKind::Pokemon,
+                    name,
+                    format!("Unknown learn method {} for move {}", method, pmove.move_.name),
+                ),
+            }
+        }
+    }
+
+    learnset.level_up.sort_by_key(|(level, _)| *level);
+
+    learnset
+}
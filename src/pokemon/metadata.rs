@@ -0,0 +1,125 @@
+use battle::pokedex::pokemon::stat::StatSet;
+use pokerust::Id;
+use serde::{Deserialize, Serialize};
+
+use crate::report::{EntryKind, Reporter};
+
+/// Species data used for catching, training and breeding a pokemon.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SpeciesMetadata {
+    pub training: TrainingMetadata,
+    pub breeding: BreedingMetadata,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TrainingMetadata {
+    /// Higher is easier to catch, from 3 to 255.
+    pub capture_rate: u8,
+    /// Happiness of the pokemon when it is caught.
+    pub base_happiness: u8,
+    /// Effort values gained for defeating the pokemon.
+    pub ev_yield: StatSet,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BreedingMetadata {
+    pub egg_groups: Vec<EggGroup>,
+    /// Steps it takes for an egg of the pokemon to hatch.
+    pub hatch_steps: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EggGroup {
+    Monster,
+    Water1,
+    Bug,
+    Flying,
+    Field,
+    Fairy,
+    Grass,
+    HumanLike,
+    Water3,
+    Mineral,
+    Amorphous,
+    Water2,
+    Ditto,
+    Dragon,
+    Undiscovered,
+}
+
+pub fn get_metadata(
+    species: &pokerust::PokemonSpecies,
+    ev_yield: StatSet,
+    generation: i16,
+    name: &str,
+    reporter: &Reporter,
+) -> SpeciesMetadata {
+    let egg_groups = species
+        .egg_groups
+        .iter()
+        .flat_map(|group| {
+            let egg_group = egg_group_from_id(group.id());
+            if egg_group.is_none() {
+                reporter.failed(EntryKind::Pokemon, name, format!("Unknown egg group {}", group.name));
+            }
+            egg_group
+        })
+        .collect();
+
+    SpeciesMetadata {
+        training: TrainingMetadata {
+            capture_rate: species.capture_rate,
+            base_happiness: species.base_happiness,
+            ev_yield,
+        },
+        breeding: BreedingMetadata {
+            egg_groups,
+            hatch_steps: egg_cycle_steps(generation) * (species.hatch_counter as u32 + 1),
+        },
+    }
+}
+
+/// Steps in each egg cycle of a generation.
+/// Eggs were added in generation 2, earlier generations use the generation 2 length.
+fn egg_cycle_steps(generation: i16) -> u32 {
+    match generation {
+        ..=3 => 256,
+        4 => 255,
+        _ => 257,
+    }
+}
+
+fn egg_group_from_id(id: i16) -> Option<EggGroup> {
+    Some(match id {
+        1 => EggGroup::Monster,
+        2 => EggGroup::Water1,
+        3 => EggGroup::Bug,
+        4 => EggGroup::Flying,
+        5 => EggGroup::Field,
+        6 => EggGroup::Fairy,
+        7 => EggGroup::Grass,
+        8 => EggGroup::HumanLike,
+        9 => EggGroup::Water3,
+        10 => EggGroup::Mineral,
+        11 => EggGroup::Amorphous,
+        12 => EggGroup::Water2,
+        13 => EggGroup::Ditto,
+        14 => EggGroup::Dragon,
+        15 => EggGroup::Undiscovered,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_egg_cycle_steps() {
+        // Bulbasaur hatches after 20 egg cycles
+        assert_eq!(egg_cycle_steps(3) * 21, 5376);
+        assert_eq!(egg_cycle_steps(4) * 21, 5355);
+        assert_eq!(egg_cycle_steps(5) * 21, 5397);
+        assert_eq!(egg_cycle_steps(1), 256);
+    }
+}
@@ -0,0 +1,138 @@
+use std::{fmt, io, path::PathBuf, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+
+/// Error that stops the whole dex from being generated.
+#[derive(Debug)]
+pub enum GenerateError {
+    /// The temporary directory for converting cries could not be created.
+    TempDir(io::Error),
+    /// The pokemon species, moves or version groups to generate from could not be listed.
+    Selection(pokerust::Error),
+    /// The configured version group does not exist.
+    UnknownVersionGroup(String),
+    /// The move override file could not be read.
+    MoveOverrides(PathBuf, io::Error),
+    /// The move override file is not a valid table of move executions.
+    InvalidMoveOverrides(PathBuf, ron::Error),
+    /// The move flag file could not be read.
+    MoveFlags(PathBuf, io::Error),
+    /// The move flag file is not valid Showdown move data.
+    InvalidMoveFlags(PathBuf, serde_json::Error),
+}
+
+impl fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TempDir(err) => write!(f, "Could not create temporary directory with error {}", err),
+            Self::Selection(err) => write!(f, "Could not list entries to generate with error {}", err),
+            Self::UnknownVersionGroup(name) => write!(f, "Version group {} does not exist", name),
+            Self::MoveOverrides(path, err) => {
+                write!(f, "Could not read move overrides at {:?} with error {}", path, err)
+            }
+            Self::InvalidMoveOverrides(path, err) => {
+                write!(f, "Could not parse move overrides at {:?} with error {}", path, err)
+            }
+            Self::MoveFlags(path, err) => {
+                write!(f, "Could not read move flags at {:?} with error {}", path, err)
+            }
+            Self::InvalidMoveFlags(path, err) => {
+                write!(f, "Could not parse move flags at {:?} with error {}", path, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::TempDir(err) => Some(err),
+            Self::Selection(err) => Some(err),
+            Self::UnknownVersionGroup(_) => None,
+            Self::MoveOverrides(_, err) => Some(err),
+            Self::InvalidMoveOverrides(_, err) => Some(err),
+            Self::MoveFlags(_, err) => Some(err),
+            Self::InvalidMoveFlags(_, err) => Some(err),
+        }
+    }
+}
+
+/// Every entry that was left out of the generated dex, or is missing some of its data.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct Report {
+    /// Entries that do not exist, and were left out on purpose.
+    pub skipped: Vec<ReportEntry>,
+    /// Entries that could not be generated, or were generated without some of their assets.
+    pub failed: Vec<ReportEntry>,
+}
+
+impl Report {
+    /// Whether every entry was generated completely.
+    pub fn is_empty(&self) -> bool {
+        self.skipped.is_empty() && self.failed.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReportEntry {
+    pub kind: EntryKind,
+    /// Name or id of the entry.
+    pub name: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EntryKind {
+    Pokemon,
+    Move,
+    Item,
+    Ability,
+    /// Texture of a pokemon, the pokemon itself is still generated.
+    Texture,
+    /// Cry of a pokemon, the pokemon itself is still generated.
+    Cry,
+    /// Evolution of a pokemon, the pokemon itself is still generated.
+    Evolution,
+    /// Effect of a move the battle engine does not support, the move itself is still generated.
+    MoveEffect,
+}
+
+impl fmt::Display for ReportEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} {}: {}", self.kind, self.name, self.reason)
+    }
+}
+
+/// Collects the report from the threads generating each kind of entry.
+#[derive(Debug, Default)]
+pub(crate) struct Reporter(Mutex<Report>);
+
+impl Reporter {
+    pub fn skipped(&self, kind: EntryKind, name: impl fmt::Display, reason: impl Into<String>) {
+        let entry = Self::entry(kind, name, reason);
+        eprintln!("Skipping {}", entry);
+        self.lock().skipped.push(entry);
+    }
+
+    pub fn failed(&self, kind: EntryKind, name: impl fmt::Display, reason: impl Into<String>) {
+        let entry = Self::entry(kind, name, reason);
+        eprintln!("Failed {}", entry);
+        self.lock().failed.push(entry);
+    }
+
+    pub fn into_inner(self) -> Report {
+        self.0.into_inner().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn entry(kind: EntryKind, name: impl fmt::Display, reason: impl Into<String>) -> ReportEntry {
+        ReportEntry {
+            kind,
+            name: name.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Report> {
+        self.0.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
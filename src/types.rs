@@ -0,0 +1,195 @@
+use battle::pokedex::types::PokemonType;
+use hashbrown::HashMap;
+use pokerust::Id;
+use serde::{Deserialize, Serialize};
+
+use crate::GenerateError;
+
+/// Effectiveness of each attacking type against each defending type.
+pub type TypeChart = HashMap<PokemonType, HashMap<PokemonType, Effectiveness>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum Effectiveness {
+    Ineffective,
+    NotEffective,
+    Effective,
+    SuperEffective,
+}
+
+/// Get the type chart of a generation, leaving out types introduced after it.
+pub fn add_types(pokerust: &pokerust::Client, generation: i16) -> Result<TypeChart, GenerateError> {
+    let types = pokerust
+        .iter::<pokerust::Type>()
+        .resolve()
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(GenerateError::Selection)?
+        .into_iter()
+        .filter(|type_| type_.generation.id() <= generation)
+        .flat_map(|type_| Some((crate::type_from_id(type_.id)?, type_)))
+        .collect::<Vec<_>>();
+
+    let mut chart = TypeChart::with_capacity(types.len());
+
+    for (attacking, type_) in &types {
+        let relations = relations_in(type_, generation);
+
+        let mut row = types
+            .iter()
+            .map(|(defending, _)| (*defending, Effectiveness::Effective))
+            .collect::<HashMap<_, _>>();
+
+        for (defending, effectiveness) in relations
+            .no_damage_to
+            .iter()
+            .map(|t| (t, Effectiveness::Ineffective))
+            .chain(relations.half_damage_to.iter().map(|t| (t, Effectiveness::NotEffective)))
+            .chain(relations.double_damage_to.iter().map(|t| (t, Effectiveness::SuperEffective)))
+        {
+            if let Some(defending) = crate::type_from_id(defending.id()) {
+                if let Some(entry) = row.get_mut(&defending) {
+                    *entry = effectiveness;
+                }
+            }
+        }
+
+        chart.insert(*attacking, row);
+    }
+
+    Ok(chart)
+}
+
+/// Get the damage relations a type had in a generation.
+fn relations_in(type_: &pokerust::Type, generation: i16) -> &pokerust::TypeRelations {
+    past_in(&type_.past_damage_relations, generation, |past| past.generation.id())
+        .map(|past| &past.damage_relations)
+        .unwrap_or(&type_.damage_relations)
+}
+
+/// Get the past entry for a generation, from entries that each hold the values up to their generation.
+pub(crate) fn past_in<T>(past: &[T], generation: i16, of: impl Fn(&T) -> i16) -> Option<&T> {
+    past.iter()
+        .filter(|entry| of(entry) >= generation)
+        .min_by_key(|entry| of(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use pokerust::{Memory, PokemonTypePast};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn resource(endpoint: &str, name: &str, id: i16) -> Value {
+        json!({ "name": name, "url": format!("https://pokeapi.co/api/v2/{}/{}/", endpoint, id) })
+    }
+
+    fn types(types: &[(&str, i16)]) -> Vec<Value> {
+        types.iter().map(|(name, id)| resource("type", name, *id)).collect()
+    }
+
+    fn relations(no: &[(&str, i16)], half: &[(&str, i16)], double: &[(&str, i16)]) -> Value {
+        json!({
+            "no_damage_to": types(no),
+            "half_damage_to": types(half),
+            "double_damage_to": types(double),
+            "no_damage_from": [],
+            "half_damage_from": [],
+            "double_damage_from": [],
+        })
+    }
+
+    fn type_(id: i16, name: &str, generation: i16, relations: Value, past: Value) -> String {
+        json!({
+            "id": id,
+            "name": name,
+            "damage_relations": relations,
+            "past_damage_relations": past,
+            "game_indices": [],
+            "generation": resource("generation", "", generation),
+            "move_damage_class": null,
+            "names": [],
+            "pokemon": [],
+            "moves": [],
+        })
+        .to_string()
+    }
+
+    const NORMAL: (&str, i16) = ("normal", 1);
+    const GHOST: (&str, i16) = ("ghost", 8);
+    const STEEL: (&str, i16) = ("steel", 9);
+    const PSYCHIC: (&str, i16) = ("psychic", 14);
+    const DARK: (&str, i16) = ("dark", 17);
+    const FAIRY: (&str, i16) = ("fairy", 18);
+
+    /// Types as PokeAPI has them today, with Steel resisting Ghost and Dark until generation 5.
+    fn client() -> pokerust::Client {
+        let all = [NORMAL, GHOST, STEEL, DARK, FAIRY];
+        let list = json!({ "count": all.len(), "next": null, "previous": null, "results": types(&all) });
+        let past = |generation: i16, relations: Value| {
+            json!([{ "generation": resource("generation", "", generation), "damage_relations": relations }])
+        };
+        pokerust::Client::new(
+            Memory::new()
+                .with("type/?offset=0&limit=100", list.to_string())
+                .with("type/1/", type_(1, "normal", 1, relations(&[GHOST], &[STEEL], &[]), json!([])))
+                .with(
+                    "type/8/",
+                    type_(
+                        8,
+                        "ghost",
+                        1,
+                        relations(&[NORMAL], &[DARK], &[GHOST, PSYCHIC]),
+                        past(5, relations(&[NORMAL], &[DARK, STEEL], &[GHOST, PSYCHIC])),
+                    ),
+                )
+                .with("type/9/", type_(9, "steel", 2, relations(&[], &[STEEL], &[FAIRY]), json!([])))
+                .with(
+                    "type/17/",
+                    type_(
+                        17,
+                        "dark",
+                        2,
+                        relations(&[], &[DARK, FAIRY], &[GHOST, PSYCHIC]),
+                        past(5, relations(&[], &[DARK, STEEL], &[GHOST, PSYCHIC])),
+                    ),
+                )
+                .with("type/18/", type_(18, "fairy", 6, relations(&[], &[STEEL], &[DARK]), json!([]))),
+        )
+    }
+
+    #[test]
+    fn test_type_chart() {
+        let chart = add_types(&client(), 3).unwrap();
+
+        assert_eq!(chart[&PokemonType::Ghost][&PokemonType::Steel], Effectiveness::NotEffective);
+        assert_eq!(chart[&PokemonType::Dark][&PokemonType::Steel], Effectiveness::NotEffective);
+        assert_eq!(chart[&PokemonType::Ghost][&PokemonType::Normal], Effectiveness::Ineffective);
+        assert_eq!(chart[&PokemonType::Dark][&PokemonType::Ghost], Effectiveness::SuperEffective);
+        assert_eq!(chart[&PokemonType::Normal][&PokemonType::Dark], Effectiveness::Effective);
+
+        assert!(!chart.contains_key(&PokemonType::Fairy));
+        assert!(chart.values().all(|row| !row.contains_key(&PokemonType::Fairy)));
+
+        let chart = add_types(&client(), 6).unwrap();
+        assert_eq!(chart[&PokemonType::Ghost][&PokemonType::Steel], Effectiveness::Effective);
+        assert_eq!(chart[&PokemonType::Dark][&PokemonType::Fairy], Effectiveness::NotEffective);
+    }
+
+    #[test]
+    fn test_past_types() {
+        // Clefairy was Normal type until Fairy was introduced in generation 6.
+        let past_types: Vec<PokemonTypePast> = serde_json::from_value(json!([{
+            "generation": resource("generation", "generation-v", 5),
+            "types": [{ "slot": 1, "type": resource("type", "normal", 1) }],
+        }]))
+        .unwrap();
+        let type_in = |generation| {
+            past_in(&past_types, generation, |past| past.generation.id())
+                .map(|past| past.types[0].type_.name.as_str())
+        };
+
+        assert_eq!(type_in(3), Some("normal"));
+        assert_eq!(type_in(5), Some("normal"));
+        assert_eq!(type_in(6), None);
+    }
+}